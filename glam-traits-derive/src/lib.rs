@@ -0,0 +1,129 @@
+/*!
+Derive macros for the traits in [glam-traits](https://docs.rs/glam-traits), enabled there with the `derive` feature.
+
+Each derive implements its trait for a single-field struct around a `glam` vector by forwarding to the field,
+along with the operators and conversions the trait requires:
+
+```ignore
+#[derive(Clone, Copy, Debug, Default, PartialEq, GVec, GVec3, SignedVec, SignedVec3, FloatVec, FloatVec3, F32Vec)]
+struct WorldPos(Vec3);
+```
+
+`Clone`, `Copy`, `Debug`, `Default`, `PartialEq` (and `Eq`, `Hash` for integer vectors) are left to the standard derives.
+The field must be named by its `glam` type, such as `Vec3` or `glam::IVec2`, rather than an alias.
+With the `serde` or `bytemuck` features of `glam-traits`, `Serialize` and `Deserialize` or `AnyBitPattern`
+(or `Zeroable` and `Pod`) must be derived as well. `PodVec` can be derived alongside `Pod`, except around `Vec3A`.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Member, Type};
+
+fn forward(input: TokenStream, name: &str) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => return Error::new_spanned(&input.ident, "expected a struct").into_compile_error().into(),
+	};
+	if !input.generics.params.is_empty() {
+		return Error::new_spanned(&input.generics, "generic structs are not supported").into_compile_error().into();
+	}
+	let (field, member) = match fields {
+		Fields::Named(named) if named.named.len() == 1 => {
+			let field = &named.named[0];
+			(field, Member::Named(field.ident.clone().unwrap()))
+		},
+		Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => (&unnamed.unnamed[0], Member::Unnamed(0.into())),
+		_ => return Error::new_spanned(fields, "expected exactly one field").into_compile_error().into(),
+	};
+	let ident = &input.ident;
+	let inner = &field.ty;
+	let Some(vector) = GlamVector::parse(inner) else {
+		return Error::new_spanned(inner, "expected a `glam` vector such as `Vec3` or `IVec2`").into_compile_error().into();
+	};
+	let extra = match (name, vector.quat) {
+		("FloatVec3", Some(quat)) => {
+			let names = [Some(quat), vector.counterpart].into_iter().flatten().map(|name| Ident::new(name, Span::call_site()));
+			quote!(#(, #names)*)
+		},
+		("FloatVec3", None) => {
+			return Error::new_spanned(inner, "expected `Vec3`, `Vec3A` or `DVec3`").into_compile_error().into();
+		},
+		("PodVec", _) if vector.padded => {
+			return Error::new_spanned(inner, "`Vec3A` has a padding lane, so it is not a `PodVec`").into_compile_error().into();
+		},
+		_ => quote!(),
+	};
+	let name = Ident::new(name, Span::call_site());
+	let scalar = Ident::new(vector.scalar, Span::call_site());
+	quote!(::glam_traits::__newtype!(#name, #ident, #inner, #member, #scalar #extra);).into()
+}
+
+/**
+What the forwarding impls need to know about a `glam` vector, recognized by name, so aliases are not supported.
+*/
+struct GlamVector {
+	scalar: &'static str,
+	/** Quaternion of a 3D float vector. */
+	quat: Option<&'static str>,
+	/** Other 3D vector used by the quaternion or affine transform, which a `FloatVec3` newtype must also convert into. */
+	counterpart: Option<&'static str>,
+	/** Whether the vector has a padding lane, as `Vec3A` does. */
+	padded: bool,
+}
+
+impl GlamVector {
+	fn parse(inner: &Type) -> Option<Self> {
+		let Type::Path(path) = inner else {
+			return None;
+		};
+		let name = path.path.segments.last()?.ident.to_string();
+		let prefix = ["Vec2", "Vec3", "Vec3A", "Vec4"].iter().find_map(|suffix| name.strip_suffix(suffix))?;
+		let scalar = match prefix {
+			"I8" => "i8",
+			"U8" => "u8",
+			"I16" => "i16",
+			"U16" => "u16",
+			"I" => "i32",
+			"U" => "u32",
+			"I64" => "i64",
+			"U64" => "u64",
+			"USize" => "usize",
+			"" => "f32",
+			"D" => "f64",
+			_ => return None,
+		};
+		let (quat, counterpart) = match name.as_str() {
+			"Vec3" => (Some("Quat"), Some("Vec3A")),
+			"Vec3A" => (Some("Quat"), Some("Vec3")),
+			"DVec3" => (Some("DQuat"), None),
+			_ => (None, None),
+		};
+		Some(Self { scalar, quat, counterpart, padded: name == "Vec3A" })
+	}
+}
+
+macro_rules! derives {
+	($($name:ident),* $(,)?) => {
+		$(
+			#[doc = concat!("Implements `", stringify!($name), "` by forwarding to the wrapped vector.")]
+			#[proc_macro_derive($name)]
+			#[allow(non_snake_case)]
+			pub fn $name(input: TokenStream) -> TokenStream {
+				forward(input, stringify!($name))
+			}
+		)*
+	};
+}
+
+derives!(
+	GVec, GVec2, GVec3, GVec4,
+	SignedVec, SignedVec2, SignedVec3, SignedVec4,
+	FloatVec, FloatVec2, FloatVec3, FloatVec4,
+	IntVec, IntVec2, IntVec3, IntVec4,
+	SIntVec, SIntVec2, SIntVec3, SIntVec4,
+	UIntVec, UIntVec2, UIntVec3, UIntVec4,
+	I8Vec, U8Vec, I16Vec, U16Vec, I32Vec, U32Vec, I64Vec, U64Vec, USizeVec, F32Vec, F64Vec,
+	PodVec,
+);
@@ -26,10 +26,56 @@ As well as for concrete types of any length:
 
 [`BVec`] is also provided to cover boolean vectors.
 
+The element types are covered by [`GScalar`], [`SignedScalar`], [`FloatScalar`], [`IntScalar`], [`SIntScalar`] and [`UIntScalar`], which bound the `Scalar` of the corresponding vector traits.
+
+Fallible slice access on [`GVec`] reports a [`SliceLenError`].
+
+[`WithScalar`] and [`WithDim`] map a vector to its counterpart with another element type or length.
+[`GVec2`], [`GVec3`] and [`GVec4`] are bound by `glam`'s swizzle traits, whose outputs are the vector itself or its `Truncated` and `Extended` types.
+
+For matrices of size "any", 2x2, 3x3 and 4x4 there are [`GMat`], [`GMat2`], [`GMat3`] and [`GMat4`].
+
+[`GQuat`] covers quaternions, and [`GAffine`], [`GAffine2`] and [`GAffine3`] cover affine transforms.
+
 Traits are implemented for the appropriate `glam` types.
+All traits are sealed; the `unsealed` feature exports `Sealed` so other types can implement them.
+With the `derive` feature, the traits can be derived for single-field structs around `glam` vectors.
+The `serde` feature adds `Serialize` and `DeserializeOwned` to the bounds of [`GVec`], [`GMat`], [`GQuat`] and [`GAffine`], but not [`BVec`], as `glam` 0.30 writes the `z` lane of `BVec4A` in place of `w` and doesn't serialize `BVec3A` and `BVec4A` with `scalar-math`. Generic types deriving `Deserialize` need `#[serde(bound = "")]`, as the bound is already implied.
+The `bytemuck` feature adds `AnyBitPattern` to the bounds of [`GVec`], and `PodVec` views slices of vectors other than [`Vec3A`] as elements or bytes.
+
+The `std` feature is enabled by default. Without it the crate is `no_std`, and the `libm` feature provides the float math
+instead. With both, the float math uses `libm`. The `alloc` feature, implied by `std`, enables [`GVec::from_slice_chunks`],
+which has a default body so implementors are unaffected by it.
+The `approx` feature adds `AbsDiffEq`, `RelativeEq` and `UlpsEq` to the bounds of [`FloatVec`], [`GMat`], [`GQuat`] and [`GAffine`],
+along with [`FloatVec::relative_eq`] and [`FloatVec::ulps_eq`]. Their own `abs_diff_eq` takes `self` by value, so method
+calls resolve to it before the `approx` method, which takes `&self`, rather than being ambiguous.
+The `rand` feature adds `RandomVec::random_range`, sampling each element from `min..=max`, and uniform sampling of unit
+circles, disks, spheres, balls and hemispheres in `RandomFloatVec2` and `RandomFloatVec3`.
+The `num-traits` feature implements `Zero`, `One` and `Bounded` on derived vectors, and `CheckedAdd`, `WrappingAdd` and
+`SaturatingAdd` on derived integer vectors. The `glam` vectors are out of scope, as the orphan rule rules out these impls
+here. It also adds `NumFloatVec`, `NumIntVec`, `NumSIntVec` and `NumUIntVec`, whose scalars are `num_traits::Float`,
+`PrimInt`, `Signed` and `Unsigned`. The scalar traits don't take these as supertraits, as their `sqrt`, `abs` and
+`count_ones` would clash with the scalar traits' own, so only code bound by the `Num` traits has to call the shared methods
+through one trait, as in `num_traits::Float::sqrt(x)`.
+The `mint` feature adds `MintVec2`, `MintVec3` and `MintVec4`, with the `Mint` (and `MintPoint`) conversion types.
+These extension traits are implemented for every type that meets their bounds, derived vectors included, so enabling
+`rand`, `num-traits` or `mint` never adds required items to the other traits.
+The `scalar-math`, `core-simd`, `debug-glam-assert`, `fast-math` and `cuda` features are passed through to `glam`.
+
+The minimum supported Rust version is 1.79, for associated type bounds such as `GVec<Scalar: FloatScalar>`.
 */
 
-use std::{
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the `std` or the `libm` feature must be enabled.");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
 	fmt::{Debug, Display},
 	iter::{Product, Sum},
 	hash::Hash,
@@ -60,15 +106,582 @@ use glam::{
 	USizeVec2, USizeVec3, USizeVec4,
 	Vec2, Vec3, Vec3A, Vec4,
 	DVec2, DVec3, DVec4,
+	Mat2, Mat3, Mat3A, Mat4,
+	DMat2, DMat3, DMat4,
+	Quat, DQuat,
+	Affine2, Affine3A,
+	DAffine2, DAffine3,
+	EulerRot,
+	Vec2Swizzles, Vec3Swizzles, Vec4Swizzles,
 };
 
 mod private {
 	pub trait Sealed {}
+
+	#[cfg(feature = "serde")]
+	pub trait Serde: serde::Serialize + serde::de::DeserializeOwned {}
+
+	#[cfg(feature = "serde")]
+	impl<T: serde::Serialize + serde::de::DeserializeOwned> Serde for T {}
+
+	#[cfg(not(feature = "serde"))]
+	pub trait Serde {}
+
+	#[cfg(not(feature = "serde"))]
+	impl<T> Serde for T {}
+
+	#[cfg(feature = "approx")]
+	pub trait Approx<S>: approx::AbsDiffEq<Epsilon = S> + approx::RelativeEq + approx::UlpsEq {}
+
+	#[cfg(feature = "approx")]
+	impl<S, T: approx::AbsDiffEq<Epsilon = S> + approx::RelativeEq + approx::UlpsEq> Approx<S> for T {}
+
+	#[cfg(not(feature = "approx"))]
+	pub trait Approx<S> {}
+
+	#[cfg(not(feature = "approx"))]
+	impl<S, T> Approx<S> for T {}
+
+	#[cfg(feature = "bytemuck")]
+	pub trait Bytemuck: bytemuck::AnyBitPattern {}
+
+	#[cfg(feature = "bytemuck")]
+	impl<T: bytemuck::AnyBitPattern> Bytemuck for T {}
+
+	#[cfg(not(feature = "bytemuck"))]
+	pub trait Bytemuck {}
+
+	#[cfg(not(feature = "bytemuck"))]
+	impl<T> Bytemuck for T {}
 }
+use private::{Serde, Approx, Bytemuck};
+/**
+Supertrait of every trait in this crate, only implementable outside of it with the `unsealed` feature.
+
+Implementors must behave like the `glam` types: operators and methods act per component (or follow the
+documented `glam` semantics), `DIM` and the array types agree on the number of components, and associated
+types such as `Scalar`, `BVec` and the `WithScalar`/`WithDim` outputs round-trip consistently.
+Enabling `unsealed` gives up the semver guarantee that methods may be added to the traits without breaking
+downstream implementations.
+*/
+#[cfg(feature = "unsealed")]
+pub use private::Sealed;
+#[cfg(not(feature = "unsealed"))]
 use private::Sealed;
 
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod newtype;
+
+#[cfg(feature = "derive")]
+pub use glam_traits_derive::*;
+
+/**
+Scalar of any type.
+
+Integer scalars get `min`, `max` and `clamp` from [`Ord`], and [`FloatScalar`] declares its own.
+*/
+pub trait GScalar
+where
+	for <'a> Self:
+		Sealed +
+		'static +
+		Clone +
+		Copy +
+		PartialEq +
+		PartialOrd +
+		Default +
+		Debug +
+		Display +
+		Add<Output = Self> +
+		Add<&'a Self, Output = Self> +
+		AddAssign +
+		AddAssign<&'a Self> +
+		Sub<Output = Self> +
+		Sub<&'a Self, Output = Self> +
+		SubAssign +
+		SubAssign<&'a Self> +
+		Mul<Output = Self> +
+		Mul<&'a Self, Output = Self> +
+		MulAssign +
+		MulAssign<&'a Self> +
+		Div<Output = Self> +
+		Div<&'a Self, Output = Self> +
+		DivAssign +
+		DivAssign<&'a Self> +
+		Rem<Output = Self> +
+		Rem<&'a Self, Output = Self> +
+		RemAssign +
+		RemAssign<&'a Self> +
+		Sum +
+		Sum<&'a Self> +
+		Product +
+		Product<&'a Self> +
+	,
+	Self::Vec2: GVec2 + GVec<Scalar = Self>,
+	Self::Vec3: GVec3 + GVec<Scalar = Self>,
+	Self::Vec4: GVec4 + GVec<Scalar = Self>,
+{
+	type Vec2;
+	type Vec3;
+	type Vec4;
+	const ZERO: Self;
+	const ONE: Self;
+	const MIN: Self;
+	const MAX: Self;
+}
+
+macro_rules! impl_gscalar {
+	(int, $type:ty, $vec2:ty, $vec3:ty, $vec4:ty) => {
+		impl Sealed for $type {}
+		impl GScalar for $type {
+			type Vec2 = $vec2;
+			type Vec3 = $vec3;
+			type Vec4 = $vec4;
+			const ZERO: Self = 0;
+			const ONE: Self = 1;
+			const MIN: Self = Self::MIN;
+			const MAX: Self = Self::MAX;
+		}
+	};
+	(float, $type:ty, $vec2:ty, $vec3:ty, $vec4:ty) => {
+		impl Sealed for $type {}
+		impl GScalar for $type {
+			type Vec2 = $vec2;
+			type Vec3 = $vec3;
+			type Vec4 = $vec4;
+			const ZERO: Self = 0.0;
+			const ONE: Self = 1.0;
+			const MIN: Self = Self::MIN;
+			const MAX: Self = Self::MAX;
+		}
+	};
+}
+
+impl_gscalar!(int, i8, I8Vec2, I8Vec3, I8Vec4);
+impl_gscalar!(int, u8, U8Vec2, U8Vec3, U8Vec4);
+impl_gscalar!(int, i16, I16Vec2, I16Vec3, I16Vec4);
+impl_gscalar!(int, u16, U16Vec2, U16Vec3, U16Vec4);
+impl_gscalar!(int, i32, IVec2, IVec3, IVec4);
+impl_gscalar!(int, u32, UVec2, UVec3, UVec4);
+impl_gscalar!(int, i64, I64Vec2, I64Vec3, I64Vec4);
+impl_gscalar!(int, u64, U64Vec2, U64Vec3, U64Vec4);
+impl_gscalar!(int, usize, USizeVec2, USizeVec3, USizeVec4);
+impl_gscalar!(float, f32, Vec2, Vec3, Vec4);
+impl_gscalar!(float, f64, DVec2, DVec3, DVec4);
+
+/**
+Calls the `std` float method, or the `libm` function with the `libm` feature, even alongside `std`.
+*/
+macro_rules! math {
+	($type:ident, $std:ident, $libm:ident, $($arg:expr),*) => {{
+		#[cfg(feature = "libm")]
+		let result = libm::Libm::<$type>::$libm($($arg),*);
+		#[cfg(not(feature = "libm"))]
+		let result = $type::$std($($arg),*);
+		result
+	}};
+}
+
+/**
+Scalar of a signed type.
+*/
+pub trait SignedScalar: GScalar + Neg<Output = Self> {
+	const NEG_ONE: Self;
+	fn abs(self) -> Self;
+	fn signum(self) -> Self;
+	fn div_euclid(self, rhs: Self) -> Self;
+	fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_signedscalar {
+	(int, $type:ty) => {
+		impl SignedScalar for $type {
+			const NEG_ONE: Self = -1;
+			fn abs(self) -> Self { self.abs() }
+			fn signum(self) -> Self { self.signum() }
+			fn div_euclid(self, rhs: Self) -> Self { self.div_euclid(rhs) }
+			fn rem_euclid(self, rhs: Self) -> Self { self.rem_euclid(rhs) }
+		}
+	};
+	(float, $type:ident) => {
+		impl SignedScalar for $type {
+			const NEG_ONE: Self = -1.0;
+			fn abs(self) -> Self { math!($type, abs, fabs, self) }
+			fn signum(self) -> Self { if self.is_nan() { self } else { math!($type, copysign, copysign, 1.0, self) } }
+			fn div_euclid(self, rhs: Self) -> Self {
+				let q = FloatScalar::trunc(self / rhs);
+				if self % rhs < 0.0 {
+					if rhs > 0.0 { q - 1.0 } else { q + 1.0 }
+				} else {
+					q
+				}
+			}
+			fn rem_euclid(self, rhs: Self) -> Self {
+				let r = self % rhs;
+				if r < 0.0 { r + SignedScalar::abs(rhs) } else { r }
+			}
+		}
+	};
+}
+
+impl_signedscalar!(int, i8);
+impl_signedscalar!(int, i16);
+impl_signedscalar!(int, i32);
+impl_signedscalar!(int, i64);
+impl_signedscalar!(float, f32);
+impl_signedscalar!(float, f64);
+
+/**
+Scalar of a floating-point type.
+*/
+pub trait FloatScalar: SignedScalar {
+	const NAN: Self;
+	const INFINITY: Self;
+	const NEG_INFINITY: Self;
+	const EPSILON: Self;
+	const MIN_POSITIVE: Self;
+	const PI: Self;
+	const TAU: Self;
+	const E: Self;
+	const FRAC_PI_2: Self;
+	const FRAC_PI_4: Self;
+	const SQRT_2: Self;
+	const LN_2: Self;
+	const LN_10: Self;
+	fn is_nan(self) -> bool;
+	fn is_finite(self) -> bool;
+	fn is_infinite(self) -> bool;
+	fn min(self, rhs: Self) -> Self;
+	fn max(self, rhs: Self) -> Self;
+	fn clamp(self, min: Self, max: Self) -> Self;
+	fn floor(self) -> Self;
+	fn ceil(self) -> Self;
+	fn round(self) -> Self;
+	fn trunc(self) -> Self;
+	fn fract(self) -> Self;
+	fn recip(self) -> Self;
+	fn copysign(self, sign: Self) -> Self;
+	fn mul_add(self, a: Self, b: Self) -> Self;
+	fn sqrt(self) -> Self;
+	fn cbrt(self) -> Self;
+	fn powi(self, n: i32) -> Self;
+	fn powf(self, n: Self) -> Self;
+	fn exp(self) -> Self;
+	fn exp2(self) -> Self;
+	fn ln(self) -> Self;
+	fn log2(self) -> Self;
+	fn log10(self) -> Self;
+	fn hypot(self, other: Self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn tan(self) -> Self;
+	fn asin(self) -> Self;
+	fn acos(self) -> Self;
+	fn atan(self) -> Self;
+	fn atan2(self, other: Self) -> Self;
+	fn sin_cos(self) -> (Self, Self);
+	fn to_degrees(self) -> Self;
+	fn to_radians(self) -> Self;
+}
+
+macro_rules! impl_floatscalar {
+	($type:ident) => {
+		impl FloatScalar for $type {
+			const NAN: Self = Self::NAN;
+			const INFINITY: Self = Self::INFINITY;
+			const NEG_INFINITY: Self = Self::NEG_INFINITY;
+			const EPSILON: Self = Self::EPSILON;
+			const MIN_POSITIVE: Self = Self::MIN_POSITIVE;
+			const PI: Self = core::$type::consts::PI;
+			const TAU: Self = core::$type::consts::TAU;
+			const E: Self = core::$type::consts::E;
+			const FRAC_PI_2: Self = core::$type::consts::FRAC_PI_2;
+			const FRAC_PI_4: Self = core::$type::consts::FRAC_PI_4;
+			const SQRT_2: Self = core::$type::consts::SQRT_2;
+			const LN_2: Self = core::$type::consts::LN_2;
+			const LN_10: Self = core::$type::consts::LN_10;
+			fn is_nan(self) -> bool { self.is_nan() }
+			fn is_finite(self) -> bool { self.is_finite() }
+			fn is_infinite(self) -> bool { self.is_infinite() }
+			fn min(self, rhs: Self) -> Self { self.min(rhs) }
+			fn max(self, rhs: Self) -> Self { self.max(rhs) }
+			fn clamp(self, min: Self, max: Self) -> Self { self.clamp(min, max) }
+			fn floor(self) -> Self { math!($type, floor, floor, self) }
+			fn ceil(self) -> Self { math!($type, ceil, ceil, self) }
+			fn round(self) -> Self { math!($type, round, round, self) }
+			fn trunc(self) -> Self { math!($type, trunc, trunc, self) }
+			fn fract(self) -> Self { self - FloatScalar::trunc(self) }
+			fn recip(self) -> Self { self.recip() }
+			fn copysign(self, sign: Self) -> Self { math!($type, copysign, copysign, self, sign) }
+			fn mul_add(self, a: Self, b: Self) -> Self { math!($type, mul_add, fma, self, a, b) }
+			fn sqrt(self) -> Self { math!($type, sqrt, sqrt, self) }
+			fn cbrt(self) -> Self { math!($type, cbrt, cbrt, self) }
+			fn powi(self, n: i32) -> Self {
+				#[cfg(feature = "libm")]
+				let result = libm::Libm::<$type>::pow(self, n as $type);
+				#[cfg(not(feature = "libm"))]
+				let result = self.powi(n);
+				result
+			}
+			fn powf(self, n: Self) -> Self { math!($type, powf, pow, self, n) }
+			fn exp(self) -> Self { math!($type, exp, exp, self) }
+			fn exp2(self) -> Self { math!($type, exp2, exp2, self) }
+			fn ln(self) -> Self { math!($type, ln, log, self) }
+			fn log2(self) -> Self { math!($type, log2, log2, self) }
+			fn log10(self) -> Self { math!($type, log10, log10, self) }
+			fn hypot(self, other: Self) -> Self { math!($type, hypot, hypot, self, other) }
+			fn sin(self) -> Self { math!($type, sin, sin, self) }
+			fn cos(self) -> Self { math!($type, cos, cos, self) }
+			fn tan(self) -> Self { math!($type, tan, tan, self) }
+			fn asin(self) -> Self { math!($type, asin, asin, self) }
+			fn acos(self) -> Self { math!($type, acos, acos, self) }
+			fn atan(self) -> Self { math!($type, atan, atan, self) }
+			fn atan2(self, other: Self) -> Self { math!($type, atan2, atan2, self, other) }
+			fn sin_cos(self) -> (Self, Self) { math!($type, sin_cos, sincos, self) }
+			fn to_degrees(self) -> Self { self.to_degrees() }
+			fn to_radians(self) -> Self { self.to_radians() }
+		}
+	};
+}
+
+impl_floatscalar!(f32);
+impl_floatscalar!(f64);
+
+/**
+Scalar of an integer type.
+*/
+pub trait IntScalar
+where
+	Self:
+		GScalar +
+		Eq +
+		Ord +
+		Hash +
+		Not<Output = Self> +
+		BitAnd<Output = Self> +
+		BitAndAssign +
+		BitOr<Output = Self> +
+		BitOrAssign +
+		BitXor<Output = Self> +
+		BitXorAssign +
+		Shl<u32, Output = Self> +
+		Shr<u32, Output = Self> +
+	,
+{
+	const BITS: u32;
+	fn checked_add(self, rhs: Self) -> Option<Self>;
+	fn checked_sub(self, rhs: Self) -> Option<Self>;
+	fn checked_mul(self, rhs: Self) -> Option<Self>;
+	fn checked_div(self, rhs: Self) -> Option<Self>;
+	fn checked_rem(self, rhs: Self) -> Option<Self>;
+	fn checked_neg(self) -> Option<Self>;
+	fn checked_pow(self, exp: u32) -> Option<Self>;
+	fn wrapping_add(self, rhs: Self) -> Self;
+	fn wrapping_sub(self, rhs: Self) -> Self;
+	fn wrapping_mul(self, rhs: Self) -> Self;
+	fn wrapping_div(self, rhs: Self) -> Self;
+	fn wrapping_rem(self, rhs: Self) -> Self;
+	fn wrapping_neg(self) -> Self;
+	fn wrapping_pow(self, exp: u32) -> Self;
+	fn saturating_add(self, rhs: Self) -> Self;
+	fn saturating_sub(self, rhs: Self) -> Self;
+	fn saturating_mul(self, rhs: Self) -> Self;
+	fn saturating_div(self, rhs: Self) -> Self;
+	fn saturating_pow(self, exp: u32) -> Self;
+	fn pow(self, exp: u32) -> Self;
+	fn count_ones(self) -> u32;
+	fn count_zeros(self) -> u32;
+	fn leading_zeros(self) -> u32;
+	fn trailing_zeros(self) -> u32;
+}
+
+macro_rules! impl_intscalar {
+	($type:ty) => {
+		impl IntScalar for $type {
+			const BITS: u32 = Self::BITS;
+			fn checked_add(self, rhs: Self) -> Option<Self> { self.checked_add(rhs) }
+			fn checked_sub(self, rhs: Self) -> Option<Self> { self.checked_sub(rhs) }
+			fn checked_mul(self, rhs: Self) -> Option<Self> { self.checked_mul(rhs) }
+			fn checked_div(self, rhs: Self) -> Option<Self> { self.checked_div(rhs) }
+			fn checked_rem(self, rhs: Self) -> Option<Self> { self.checked_rem(rhs) }
+			fn checked_neg(self) -> Option<Self> { self.checked_neg() }
+			fn checked_pow(self, exp: u32) -> Option<Self> { self.checked_pow(exp) }
+			fn wrapping_add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+			fn wrapping_sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+			fn wrapping_mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+			fn wrapping_div(self, rhs: Self) -> Self { self.wrapping_div(rhs) }
+			fn wrapping_rem(self, rhs: Self) -> Self { self.wrapping_rem(rhs) }
+			fn wrapping_neg(self) -> Self { self.wrapping_neg() }
+			fn wrapping_pow(self, exp: u32) -> Self { self.wrapping_pow(exp) }
+			fn saturating_add(self, rhs: Self) -> Self { self.saturating_add(rhs) }
+			fn saturating_sub(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
+			fn saturating_mul(self, rhs: Self) -> Self { self.saturating_mul(rhs) }
+			fn saturating_div(self, rhs: Self) -> Self { self.saturating_div(rhs) }
+			fn saturating_pow(self, exp: u32) -> Self { self.saturating_pow(exp) }
+			fn pow(self, exp: u32) -> Self { self.pow(exp) }
+			fn count_ones(self) -> u32 { self.count_ones() }
+			fn count_zeros(self) -> u32 { self.count_zeros() }
+			fn leading_zeros(self) -> u32 { self.leading_zeros() }
+			fn trailing_zeros(self) -> u32 { self.trailing_zeros() }
+		}
+	};
+}
+
+impl_intscalar!(i8);
+impl_intscalar!(u8);
+impl_intscalar!(i16);
+impl_intscalar!(u16);
+impl_intscalar!(i32);
+impl_intscalar!(u32);
+impl_intscalar!(i64);
+impl_intscalar!(u64);
+impl_intscalar!(usize);
+
+/**
+Scalar of a signed integer type.
+*/
+pub trait SIntScalar: IntScalar + SignedScalar {}
+
+impl SIntScalar for i8 {}
+impl SIntScalar for i16 {}
+impl SIntScalar for i32 {}
+impl SIntScalar for i64 {}
+
+/**
+Scalar of an unsigned integer type.
+*/
+pub trait UIntScalar: IntScalar {
+	fn is_power_of_two(self) -> bool;
+	fn next_power_of_two(self) -> Self;
+}
+
+macro_rules! impl_uintscalar {
+	($type:ty) => {
+		impl UIntScalar for $type {
+			fn is_power_of_two(self) -> bool { self.is_power_of_two() }
+			fn next_power_of_two(self) -> Self { self.next_power_of_two() }
+		}
+	};
+}
+
+impl_uintscalar!(u8);
+impl_uintscalar!(u16);
+impl_uintscalar!(u32);
+impl_uintscalar!(u64);
+impl_uintscalar!(usize);
+
+/**
+Error returned by the fallible slice methods of [`GVec`] when a slice has the wrong length.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SliceLenError {
+	/** Length the slice needed. */
+	pub expected: usize,
+	/** Length the slice had. */
+	pub actual: usize,
+}
+
+impl SliceLenError {
+	fn check(ok: bool, expected: usize, actual: usize) -> Result<(), Self> {
+		if ok { Ok(()) } else { Err(Self { expected, actual }) }
+	}
+}
+
+impl Display for SliceLenError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "expected slice of length {}, got {}", self.expected, self.actual)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceLenError {}
+
+/**
+Conversion between element types used by the checked and saturating vector conversions.
+*/
+trait ScalarCast<T> {
+	fn try_cast(self) -> Option<T>;
+	fn saturating_cast(self) -> T;
+}
+
+macro_rules! impl_scalarcast {
+	(int, $from:ty, $($to:ty),*) => {
+		$(
+			impl ScalarCast<$to> for $from {
+				fn try_cast(self) -> Option<$to> { <$to>::try_from(self).ok() }
+				fn saturating_cast(self) -> $to { (self as i128).clamp(<$to>::MIN as i128, <$to>::MAX as i128) as $to }
+			}
+		)*
+		impl ScalarCast<f32> for $from {
+			fn try_cast(self) -> Option<f32> {
+				let f = self as f32;
+				if f as i128 == self as i128 { Some(f) } else { None }
+			}
+			fn saturating_cast(self) -> f32 { self as f32 }
+		}
+		impl ScalarCast<f64> for $from {
+			fn try_cast(self) -> Option<f64> {
+				let f = self as f64;
+				if f as i128 == self as i128 { Some(f) } else { None }
+			}
+			fn saturating_cast(self) -> f64 { self as f64 }
+		}
+	};
+	(float, $from:ty, $($to:ty),*) => {
+		$(
+			impl ScalarCast<$to> for $from {
+				fn try_cast(self) -> Option<$to> {
+					let trunc = FloatScalar::trunc(self);
+					if trunc >= <$to>::MIN as $from && trunc < <$to>::MAX as $from + 1.0 {
+						Some(self as $to)
+					} else {
+						None
+					}
+				}
+				fn saturating_cast(self) -> $to { self as $to }
+			}
+		)*
+		impl ScalarCast<f32> for $from {
+			fn try_cast(self) -> Option<f32> {
+				if self.is_finite() && !(f32::MIN as $from..=f32::MAX as $from).contains(&self) {
+					None
+				} else {
+					Some(self as f32)
+				}
+			}
+			fn saturating_cast(self) -> f32 {
+				if self.is_finite() {
+					self.clamp(f32::MIN as $from, f32::MAX as $from) as f32
+				} else {
+					self as f32
+				}
+			}
+		}
+		impl ScalarCast<f64> for $from {
+			fn try_cast(self) -> Option<f64> { Some(self as f64) }
+			fn saturating_cast(self) -> f64 { self as f64 }
+		}
+	};
+}
+
+impl_scalarcast!(int, i8, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, u8, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, i16, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, u16, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, i32, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, u32, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, i64, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, u64, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(int, usize, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(float, f32, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+impl_scalarcast!(float, f64, i8, u8, i16, u16, i32, u32, i64, u64, usize);
+
 /**
 Vector of any length whose elements are [`bool`].
+
+The `serde` feature doesn't add `Serialize` and `DeserializeOwned` to these bounds, nor to [`GVec::BVec`]:
+`glam` 0.30 serializes the `z` lane of `BVec4A` in place of `w`, and doesn't serialize `BVec3A` and `BVec4A` with `scalar-math`.
 */
 pub trait BVec
 where
@@ -92,53 +705,93 @@ where
 		From<Self::Array> +
 		Into<Self::Array> +
 	,
-	Self::Array: Index<usize, Output = bool>,
+	Self::Array: Index<usize, Output = bool> + IntoIterator<Item = bool>,
+	Self::Unaligned: BVec<Array = Self::Array>,
+	Self::Aligned: BVec<Array = Self::Array>,
 {
 	type Array;
+	type Unaligned;
+	type Aligned;
 	const FALSE: Self;
 	const TRUE: Self;
 	const DIM: usize;
 	fn splat(v: bool) -> Self;
 	fn from_array(a: Self::Array) -> Self;
+	fn to_array(&self) -> Self::Array;
+	fn from_bitmask(mask: u32) -> Self;
 	fn bitmask(self) -> u32;
 	fn any(self) -> bool;
 	fn all(self) -> bool;
+	fn count_true(self) -> u32;
+	fn first_true(self) -> Option<usize>;
+	fn first_false(self) -> Option<usize>;
 	fn test(&self, index: usize) -> bool;
 	fn set(&mut self, index: usize, value: bool);
+	fn iter(&self) -> <Self::Array as IntoIterator>::IntoIter;
+	fn map<F: FnMut(bool) -> bool>(self, f: F) -> Self;
+	fn and_not(self, rhs: Self) -> Self;
+	fn cmpeq(self, rhs: Self) -> Self;
+	fn to_unaligned(self) -> Self::Unaligned;
+	fn to_aligned(self) -> Self::Aligned;
 }
 
 macro_rules! impl_gbvec {
-	($type:ty, $dim:literal) => {
+	($type:ty, $unaligned:ty, $aligned:ty, $dim:literal) => {
 		impl Sealed for $type {}
 		impl BVec for $type {
 			type Array = [bool; $dim];
+			type Unaligned = $unaligned;
+			type Aligned = $aligned;
 			const FALSE: Self = Self::FALSE;
 			const TRUE: Self = Self::TRUE;
 			const DIM: usize = $dim;
 			fn splat(v: bool) -> Self { Self::splat(v) }
 			fn from_array(a: Self::Array) -> Self { Self::from_array(a) }
+			fn to_array(&self) -> Self::Array { (*self).into() }
+			fn from_bitmask(mask: u32) -> Self { Self::from_array(core::array::from_fn(|i| mask & (1 << i) != 0)) }
 			fn bitmask(self) -> u32 { self.bitmask() }
 			fn any(self) -> bool { self.any() }
 			fn all(self) -> bool { self.all() }
+			fn count_true(self) -> u32 { self.bitmask().count_ones() }
+			fn first_true(self) -> Option<usize> {
+				let mask = self.bitmask();
+				if mask == 0 { None } else { Some(mask.trailing_zeros() as usize) }
+			}
+			fn first_false(self) -> Option<usize> { BVec::first_true(!self) }
 			fn test(&self, index: usize) -> bool { self.test(index) }
 			fn set(&mut self, index: usize, value: bool) { self.set(index, value) }
+			fn iter(&self) -> <Self::Array as IntoIterator>::IntoIter { BVec::to_array(self).into_iter() }
+			fn map<F: FnMut(bool) -> bool>(self, f: F) -> Self { Self::from_array(BVec::to_array(&self).map(f)) }
+			fn and_not(self, rhs: Self) -> Self { self & !rhs }
+			fn cmpeq(self, rhs: Self) -> Self { !(self ^ rhs) }
+			fn to_unaligned(self) -> Self::Unaligned { <$unaligned>::from_array(self.into()) }
+			fn to_aligned(self) -> Self::Aligned { <$aligned>::from_array(self.into()) }
 		}
 	};
 }
 
-impl_gbvec!(BVec2, 2);
-impl_gbvec!(BVec3, 3);
-impl_gbvec!(BVec3A, 3);
-impl_gbvec!(BVec4, 4);
-impl_gbvec!(BVec4A, 4);
+impl_gbvec!(BVec2, BVec2, BVec2, 2);
+impl_gbvec!(BVec3, BVec3, BVec3A, 3);
+impl_gbvec!(BVec3A, BVec3, BVec3A, 3);
+impl_gbvec!(BVec4, BVec4, BVec4A, 4);
+impl_gbvec!(BVec4A, BVec4, BVec4A, 4);
 
 /**
 Generic vector of any length.
+
+The `try_as_*` conversions return `None` unless every element can be converted:
+floats are truncated toward zero in an integer target, but NaN, infinite and out-of-range values are rejected,
+and integers must be exactly representable in a float target.
+From `f64` to `f32`, finite values must be within the range of `f32` and are rounded to the nearest `f32`,
+while NaN and infinities are passed through.
+The `saturating_as_*` conversions clamp to the range of the target, with NaN becoming zero in an integer target.
 */
 pub trait GVec
 where
 	for <'a> Self:
 		Sealed +
+		Serde +
+		Bytemuck +
 		Clone +
 		Copy +
 		PartialEq +
@@ -196,11 +849,20 @@ where
 		Index<usize, Output = Self::Scalar> +
 		IndexMut<usize, Output = Self::Scalar> +
 		From<Self::BVec> +
+		WithScalar<i8, Output = Self::I8Vec> +
+		WithScalar<u8, Output = Self::U8Vec> +
+		WithScalar<i16, Output = Self::I16Vec> +
+		WithScalar<u16, Output = Self::U16Vec> +
+		WithScalar<i32, Output = Self::I32Vec> +
+		WithScalar<u32, Output = Self::U32Vec> +
+		WithScalar<i64, Output = Self::I64Vec> +
+		WithScalar<u64, Output = Self::U64Vec> +
+		WithScalar<usize, Output = Self::USizeVec> +
+		WithScalar<f32, Output = Self::F32Vec> +
+		WithScalar<f64, Output = Self::F64Vec> +
 	,
 	for <'a> Self::Scalar:
-		'static +
-		Copy +
-		PartialOrd +
+		GScalar +
 		Add<Self, Output = Self> +
 		Add<&'a Self, Output = Self> +
 		Sub<Self, Output = Self> +
@@ -214,7 +876,7 @@ where
 	,
 	Self::BVec: BVec,
 	Self::Axes: Index<usize, Output = Self>,
-	Self::Array: Index<usize, Output = Self::Scalar>,
+	Self::Array: Index<usize, Output = Self::Scalar> + IntoIterator<Item = Self::Scalar>,
 	Self::I8Vec: I8Vec,
 	Self::U8Vec: U8Vec,
 	Self::I16Vec: I16Vec,
@@ -250,10 +912,24 @@ where
 	const DIM: usize;
 	fn splat(v: Self::Scalar) -> Self;
 	fn map<F: Fn(Self::Scalar) -> Self::Scalar>(self, f: F) -> Self;
+	fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self;
+	fn iter(&self) -> core::slice::Iter<'_, Self::Scalar>;
+	fn into_iter(self) -> <Self::Array as IntoIterator>::IntoIter;
+	fn zip_map<F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar>(self, rhs: Self, f: F) -> Self;
+	fn map_to<W: GScalar, F: FnMut(Self::Scalar) -> W>(self, f: F) -> <Self as WithScalar<W>>::Output where Self: WithScalar<W>;
+	fn fold<B, F: FnMut(B, Self::Scalar) -> B>(self, init: B, f: F) -> B;
 	fn from_array(a: Self::Array) -> Self;
 	fn to_array(&self) -> Self::Array;
 	fn from_slice(slice: &[Self::Scalar]) -> Self;
 	fn write_to_slice(self, slice: &mut [Self::Scalar]);
+	fn try_from_slice(slice: &[Self::Scalar]) -> Result<Self, SliceLenError>;
+	fn try_write_to_slice(self, slice: &mut [Self::Scalar]) -> Result<(), SliceLenError>;
+	#[cfg(feature = "alloc")]
+	fn from_slice_chunks(slice: &[Self::Scalar]) -> Result<Vec<Self>, SliceLenError> {
+		SliceLenError::check(slice.len() % Self::DIM == 0, slice.len().next_multiple_of(Self::DIM), slice.len())?;
+		Ok(slice.chunks_exact(Self::DIM).map(Self::from_slice).collect())
+	}
+	fn write_chunks_to_slice(vecs: &[Self], slice: &mut [Self::Scalar]) -> Result<(), SliceLenError>;
 	fn dot(self, rhs: Self) -> Self::Scalar;
 	fn dot_into_vec(self, rhs: Self) -> Self;
 	fn min(self, rhs: Self) -> Self;
@@ -284,6 +960,28 @@ where
 	fn as_usizevec(&self) -> Self::USizeVec;
 	fn as_vec(&self) -> Self::F32Vec;
 	fn as_dvec(&self) -> Self::F64Vec;
+	fn try_as_i8vec(&self) -> Option<Self::I8Vec>;
+	fn try_as_u8vec(&self) -> Option<Self::U8Vec>;
+	fn try_as_i16vec(&self) -> Option<Self::I16Vec>;
+	fn try_as_u16vec(&self) -> Option<Self::U16Vec>;
+	fn try_as_ivec(&self) -> Option<Self::I32Vec>;
+	fn try_as_uvec(&self) -> Option<Self::U32Vec>;
+	fn try_as_i64vec(&self) -> Option<Self::I64Vec>;
+	fn try_as_u64vec(&self) -> Option<Self::U64Vec>;
+	fn try_as_usizevec(&self) -> Option<Self::USizeVec>;
+	fn try_as_vec(&self) -> Option<Self::F32Vec>;
+	fn try_as_dvec(&self) -> Option<Self::F64Vec>;
+	fn saturating_as_i8vec(&self) -> Self::I8Vec;
+	fn saturating_as_u8vec(&self) -> Self::U8Vec;
+	fn saturating_as_i16vec(&self) -> Self::I16Vec;
+	fn saturating_as_u16vec(&self) -> Self::U16Vec;
+	fn saturating_as_ivec(&self) -> Self::I32Vec;
+	fn saturating_as_uvec(&self) -> Self::U32Vec;
+	fn saturating_as_i64vec(&self) -> Self::I64Vec;
+	fn saturating_as_u64vec(&self) -> Self::U64Vec;
+	fn saturating_as_usizevec(&self) -> Self::USizeVec;
+	fn saturating_as_vec(&self) -> Self::F32Vec;
+	fn saturating_as_dvec(&self) -> Self::F64Vec;
 }
 
 macro_rules! as_types {
@@ -345,28 +1043,90 @@ macro_rules! impl_as {
 	};
 }
 
-macro_rules! impl_gvec {
-	($type:ty, $scalar:ty, $bvec:ty, $dim:tt) => {
-		impl Sealed for $type {}
-		impl GVec for $type {
-			type Scalar = $scalar;
-			type BVec = $bvec;
-			type Axes = [Self; $dim];
-			type Array = [$scalar; $dim];
-			as_types!($dim);
-			const ZERO: Self = Self::ZERO;
-			const ONE: Self = Self::ONE;
-			const MIN: Self = Self::MIN;
-			const MAX: Self = Self::MAX;
-			const AXES: Self::Axes = Self::AXES;
-			const DIM: usize = $dim;
-			fn splat(v: Self::Scalar) -> Self { Self::splat(v) }
-			fn map<F: Fn(Self::Scalar) -> Self::Scalar>(self, f: F) -> Self { self.map(f) }
-			fn from_array(a: Self::Array) -> Self { Self::from_array(a) }
-			fn to_array(&self) -> Self::Array { self.to_array() }
-			fn from_slice(slice: &[Self::Scalar]) -> Self { Self::from_slice(slice) }
-			fn write_to_slice(self, slice: &mut [Self::Scalar]) { self.write_to_slice(slice) }
-			fn dot(self, rhs: Self) -> Self::Scalar { self.dot(rhs) }
+macro_rules! impl_try_as {
+	($fn_name:ident, $out:ty, ($($comp:ident),*)) => {
+		fn $fn_name(&self) -> Option<$out> {
+			Some(<$out>::new($(ScalarCast::try_cast(self.$comp)?),*))
+		}
+	};
+	($fn_name:ident, $out:ty, 2) => {
+		impl_try_as!($fn_name, $out, (x, y));
+	};
+	($fn_name:ident, $out:ty, 3) => {
+		impl_try_as!($fn_name, $out, (x, y, z));
+	};
+	($fn_name:ident, $out:ty, 4) => {
+		impl_try_as!($fn_name, $out, (x, y, z, w));
+	};
+}
+
+macro_rules! impl_saturating_as {
+	($fn_name:ident, $out:ty, ($($comp:ident),*)) => {
+		fn $fn_name(&self) -> $out {
+			<$out>::new($(ScalarCast::saturating_cast(self.$comp)),*)
+		}
+	};
+	($fn_name:ident, $out:ty, 2) => {
+		impl_saturating_as!($fn_name, $out, (x, y));
+	};
+	($fn_name:ident, $out:ty, 3) => {
+		impl_saturating_as!($fn_name, $out, (x, y, z));
+	};
+	($fn_name:ident, $out:ty, 4) => {
+		impl_saturating_as!($fn_name, $out, (x, y, z, w));
+	};
+}
+
+macro_rules! impl_gvec {
+	($type:ty, $scalar:ty, $bvec:ty, $dim:tt) => {
+		impl Sealed for $type {}
+		impl GVec for $type {
+			type Scalar = $scalar;
+			type BVec = $bvec;
+			type Axes = [Self; $dim];
+			type Array = [$scalar; $dim];
+			as_types!($dim);
+			const ZERO: Self = Self::ZERO;
+			const ONE: Self = Self::ONE;
+			const MIN: Self = Self::MIN;
+			const MAX: Self = Self::MAX;
+			const AXES: Self::Axes = Self::AXES;
+			const DIM: usize = $dim;
+			fn splat(v: Self::Scalar) -> Self { Self::splat(v) }
+			fn map<F: Fn(Self::Scalar) -> Self::Scalar>(self, f: F) -> Self { self.map(f) }
+			fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self { Self::from_array(core::array::from_fn(f)) }
+			fn iter(&self) -> core::slice::Iter<'_, Self::Scalar> { AsRef::<[$scalar; $dim]>::as_ref(self).iter() }
+			fn into_iter(self) -> <Self::Array as IntoIterator>::IntoIter { self.to_array().into_iter() }
+			fn zip_map<F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar>(self, rhs: Self, mut f: F) -> Self {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				Self::from_fn(|i| f(a[i], b[i]))
+			}
+			fn map_to<W: GScalar, F: FnMut(Self::Scalar) -> W>(self, mut f: F) -> <Self as WithScalar<W>>::Output where Self: WithScalar<W> {
+				let a = self.to_array();
+				<Self as WithScalar<W>>::Output::from_fn(|i| f(a[i]))
+			}
+			fn fold<B, F: FnMut(B, Self::Scalar) -> B>(self, init: B, f: F) -> B { self.to_array().into_iter().fold(init, f) }
+			fn from_array(a: Self::Array) -> Self { Self::from_array(a) }
+			fn to_array(&self) -> Self::Array { self.to_array() }
+			fn from_slice(slice: &[Self::Scalar]) -> Self { Self::from_slice(slice) }
+			fn write_to_slice(self, slice: &mut [Self::Scalar]) { self.write_to_slice(slice) }
+			fn try_from_slice(slice: &[Self::Scalar]) -> Result<Self, SliceLenError> {
+				SliceLenError::check(slice.len() >= $dim, $dim, slice.len())?;
+				Ok(Self::from_slice(slice))
+			}
+			fn try_write_to_slice(self, slice: &mut [Self::Scalar]) -> Result<(), SliceLenError> {
+				SliceLenError::check(slice.len() >= $dim, $dim, slice.len())?;
+				self.write_to_slice(slice);
+				Ok(())
+			}
+			fn write_chunks_to_slice(vecs: &[Self], slice: &mut [Self::Scalar]) -> Result<(), SliceLenError> {
+				SliceLenError::check(slice.len() >= vecs.len() * $dim, vecs.len() * $dim, slice.len())?;
+				for (v, chunk) in vecs.iter().zip(slice.chunks_exact_mut($dim)) {
+					v.write_to_slice(chunk);
+				}
+				Ok(())
+			}
+			fn dot(self, rhs: Self) -> Self::Scalar { self.dot(rhs) }
 			fn dot_into_vec(self, rhs: Self) -> Self { self.dot_into_vec(rhs) }
 			fn min(self, rhs: Self) -> Self { self.min(rhs) }
 			fn max(self, rhs: Self) -> Self { self.max(rhs) }
@@ -396,10 +1156,31 @@ macro_rules! impl_gvec {
 			impl_as!(as_usizevec, Self::USizeVec, $dim);
 			impl_as!(as_vec, Self::F32Vec, $dim);
 			impl_as!(as_dvec, Self::F64Vec, $dim);
+			impl_try_as!(try_as_i8vec, Self::I8Vec, $dim);
+			impl_try_as!(try_as_u8vec, Self::U8Vec, $dim);
+			impl_try_as!(try_as_i16vec, Self::I16Vec, $dim);
+			impl_try_as!(try_as_u16vec, Self::U16Vec, $dim);
+			impl_try_as!(try_as_ivec, Self::I32Vec, $dim);
+			impl_try_as!(try_as_uvec, Self::U32Vec, $dim);
+			impl_try_as!(try_as_i64vec, Self::I64Vec, $dim);
+			impl_try_as!(try_as_u64vec, Self::U64Vec, $dim);
+			impl_try_as!(try_as_usizevec, Self::USizeVec, $dim);
+			impl_try_as!(try_as_vec, Self::F32Vec, $dim);
+			impl_try_as!(try_as_dvec, Self::F64Vec, $dim);
+			impl_saturating_as!(saturating_as_i8vec, Self::I8Vec, $dim);
+			impl_saturating_as!(saturating_as_u8vec, Self::U8Vec, $dim);
+			impl_saturating_as!(saturating_as_i16vec, Self::I16Vec, $dim);
+			impl_saturating_as!(saturating_as_u16vec, Self::U16Vec, $dim);
+			impl_saturating_as!(saturating_as_ivec, Self::I32Vec, $dim);
+			impl_saturating_as!(saturating_as_uvec, Self::U32Vec, $dim);
+			impl_saturating_as!(saturating_as_i64vec, Self::I64Vec, $dim);
+			impl_saturating_as!(saturating_as_u64vec, Self::U64Vec, $dim);
+			impl_saturating_as!(saturating_as_usizevec, Self::USizeVec, $dim);
+			impl_saturating_as!(saturating_as_vec, Self::F32Vec, $dim);
+			impl_saturating_as!(saturating_as_dvec, Self::F64Vec, $dim);
 		}
 	};
 }
-pub(crate) use impl_gvec;
 
 impl_gvec!(I8Vec2, i8, BVec2, 2);
 impl_gvec!(I8Vec3, i8, BVec3, 3);
@@ -431,11 +1212,176 @@ impl_gvec!(USizeVec4, usize, BVec4, 4);
 impl_gvec!(Vec2, f32, BVec2, 2);
 impl_gvec!(Vec3, f32, BVec3, 3);
 impl_gvec!(Vec3A, f32, BVec3A, 3);
+// `glam` uses `BVec4` as the mask of `Vec4` with `scalar-math`.
+#[cfg(not(feature = "scalar-math"))]
 impl_gvec!(Vec4, f32, BVec4A, 4);
+#[cfg(feature = "scalar-math")]
+impl_gvec!(Vec4, f32, BVec4, 4);
 impl_gvec!(DVec2, f64, BVec2, 2);
 impl_gvec!(DVec3, f64, BVec3, 3);
 impl_gvec!(DVec4, f64, BVec4, 4);
 
+/**
+Vector without padding, whose slices can be viewed as its elements or bytes.
+
+Implemented for every `glam` vector except [`Vec3A`]. Its fourth lane is padding, which a slice of elements would
+interleave with the real ones. It is also only `Pod` with SIMD, as the padding is uninitialized with `scalar-math`,
+which is why [`GVec`] itself is only bound by [`AnyBitPattern`](bytemuck::AnyBitPattern).
+*/
+#[cfg(feature = "bytemuck")]
+pub trait PodVec: GVec<Scalar: bytemuck::Pod> + bytemuck::Pod {
+	fn slice_as_scalars(vecs: &[Self]) -> &[Self::Scalar];
+	fn slice_as_scalars_mut(vecs: &mut [Self]) -> &mut [Self::Scalar];
+	fn slice_as_bytes(vecs: &[Self]) -> &[u8];
+}
+
+macro_rules! impl_podvec {
+	($type:ty) => {
+		#[cfg(feature = "bytemuck")]
+		impl PodVec for $type {
+			fn slice_as_scalars(vecs: &[Self]) -> &[Self::Scalar] { bytemuck::cast_slice(vecs) }
+			fn slice_as_scalars_mut(vecs: &mut [Self]) -> &mut [Self::Scalar] { bytemuck::cast_slice_mut(vecs) }
+			fn slice_as_bytes(vecs: &[Self]) -> &[u8] { bytemuck::cast_slice(vecs) }
+		}
+	};
+}
+
+impl_podvec!(I8Vec2);
+impl_podvec!(I8Vec3);
+impl_podvec!(I8Vec4);
+impl_podvec!(U8Vec2);
+impl_podvec!(U8Vec3);
+impl_podvec!(U8Vec4);
+impl_podvec!(I16Vec2);
+impl_podvec!(I16Vec3);
+impl_podvec!(I16Vec4);
+impl_podvec!(U16Vec2);
+impl_podvec!(U16Vec3);
+impl_podvec!(U16Vec4);
+impl_podvec!(IVec2);
+impl_podvec!(IVec3);
+impl_podvec!(IVec4);
+impl_podvec!(UVec2);
+impl_podvec!(UVec3);
+impl_podvec!(UVec4);
+impl_podvec!(I64Vec2);
+impl_podvec!(I64Vec3);
+impl_podvec!(I64Vec4);
+impl_podvec!(U64Vec2);
+impl_podvec!(U64Vec3);
+impl_podvec!(U64Vec4);
+impl_podvec!(USizeVec2);
+impl_podvec!(USizeVec3);
+impl_podvec!(USizeVec4);
+impl_podvec!(Vec2);
+impl_podvec!(Vec3);
+impl_podvec!(Vec4);
+impl_podvec!(DVec2);
+impl_podvec!(DVec3);
+impl_podvec!(DVec4);
+
+/**
+Maps a vector to the vector of the same length whose elements are `S`.
+
+`<Vec3 as WithScalar<f64>>::Output` is [`DVec3`]. [`Vec3A`] maps like [`Vec3`], so even
+`<Vec3A as WithScalar<f32>>::Output` is [`Vec3`] rather than [`Vec3A`] itself.
+*/
+pub trait WithScalar<S>
+where
+	Self: Sealed,
+	S: GScalar,
+	Self::Output: GVec<Scalar = S>,
+{
+	type Output;
+}
+
+/**
+Maps a vector to the vector of length `N` with the same element type.
+
+`<IVec2 as WithDim<3>>::Output` is [`IVec3`].
+
+Implemented for every [`GVec`] through [`GScalar::Vec2`], [`GScalar::Vec3`] and [`GScalar::Vec4`].
+*/
+pub trait WithDim<const N: usize>
+where
+	Self: Sealed,
+	Self::Output: GVec,
+{
+	type Output;
+}
+
+impl<T: GVec> WithDim<2> for T {
+	type Output = <T::Scalar as GScalar>::Vec2;
+}
+
+impl<T: GVec> WithDim<3> for T {
+	type Output = <T::Scalar as GScalar>::Vec3;
+}
+
+impl<T: GVec> WithDim<4> for T {
+	type Output = <T::Scalar as GScalar>::Vec4;
+}
+
+macro_rules! impl_with {
+	($type:ty, $i8:ty, $u8:ty, $i16:ty, $u16:ty, $i32:ty, $u32:ty, $i64:ty, $u64:ty, $usize:ty, $f32:ty, $f64:ty) => {
+		impl WithScalar<i8> for $type { type Output = $i8; }
+		impl WithScalar<u8> for $type { type Output = $u8; }
+		impl WithScalar<i16> for $type { type Output = $i16; }
+		impl WithScalar<u16> for $type { type Output = $u16; }
+		impl WithScalar<i32> for $type { type Output = $i32; }
+		impl WithScalar<u32> for $type { type Output = $u32; }
+		impl WithScalar<i64> for $type { type Output = $i64; }
+		impl WithScalar<u64> for $type { type Output = $u64; }
+		impl WithScalar<usize> for $type { type Output = $usize; }
+		impl WithScalar<f32> for $type { type Output = $f32; }
+		impl WithScalar<f64> for $type { type Output = $f64; }
+	};
+	($type:ty, 2) => {
+		impl_with!($type, I8Vec2, U8Vec2, I16Vec2, U16Vec2, IVec2, UVec2, I64Vec2, U64Vec2, USizeVec2, Vec2, DVec2);
+	};
+	($type:ty, 3) => {
+		impl_with!($type, I8Vec3, U8Vec3, I16Vec3, U16Vec3, IVec3, UVec3, I64Vec3, U64Vec3, USizeVec3, Vec3, DVec3);
+	};
+	($type:ty, 4) => {
+		impl_with!($type, I8Vec4, U8Vec4, I16Vec4, U16Vec4, IVec4, UVec4, I64Vec4, U64Vec4, USizeVec4, Vec4, DVec4);
+	};
+}
+
+impl_with!(I8Vec2, 2);
+impl_with!(I8Vec3, 3);
+impl_with!(I8Vec4, 4);
+impl_with!(U8Vec2, 2);
+impl_with!(U8Vec3, 3);
+impl_with!(U8Vec4, 4);
+impl_with!(I16Vec2, 2);
+impl_with!(I16Vec3, 3);
+impl_with!(I16Vec4, 4);
+impl_with!(U16Vec2, 2);
+impl_with!(U16Vec3, 3);
+impl_with!(U16Vec4, 4);
+impl_with!(IVec2, 2);
+impl_with!(IVec3, 3);
+impl_with!(IVec4, 4);
+impl_with!(UVec2, 2);
+impl_with!(UVec3, 3);
+impl_with!(UVec4, 4);
+impl_with!(I64Vec2, 2);
+impl_with!(I64Vec3, 3);
+impl_with!(I64Vec4, 4);
+impl_with!(U64Vec2, 2);
+impl_with!(U64Vec3, 3);
+impl_with!(U64Vec4, 4);
+impl_with!(USizeVec2, 2);
+impl_with!(USizeVec3, 3);
+impl_with!(USizeVec4, 4);
+impl_with!(Vec2, 2);
+impl_with!(Vec3, 3);
+impl_with!(Vec3A, 3);
+impl_with!(Vec4, 4);
+impl_with!(DVec2, 2);
+impl_with!(DVec3, 3);
+impl_with!(DVec4, 4);
+
 /**
 Generic vector of length 2.
 */
@@ -457,9 +1403,11 @@ where
 			F32Vec = Vec2,
 			F64Vec = DVec2,
 		> +
+		Vec2Swizzles<Vec3 = Self::Extended, Vec4 = <Self::Extended as GVec3>::Extended> +
 		From<(Self::Scalar, Self::Scalar)> +
 		Into<(Self::Scalar, Self::Scalar)> +
 	,
+	Self::Extended: GVec3 + GVec<Scalar = <Self as GVec>::Scalar>,
 {
 	type Extended;
 	const X: Self;
@@ -487,7 +1435,6 @@ macro_rules! impl_gvec2 {
 		}
 	};
 }
-pub(crate) use impl_gvec2;
 
 impl_gvec2!(I8Vec2, I8Vec3);
 impl_gvec2!(U8Vec2, U8Vec3);
@@ -522,9 +1469,12 @@ where
 			F32Vec = Vec3,
 			F64Vec = DVec3,
 		> +
+		Vec3Swizzles<Vec2 = Self::Truncated, Vec4 = Self::Extended> +
 		From<(Self::Scalar, Self::Scalar, Self::Scalar)> +
 		Into<(Self::Scalar, Self::Scalar, Self::Scalar)> +
 	,
+	Self::Extended: GVec4 + GVec<Scalar = <Self as GVec>::Scalar>,
+	Self::Truncated: GVec2 + GVec<Scalar = <Self as GVec>::Scalar>,
 {
 	type Extended;
 	type Truncated;
@@ -564,7 +1514,6 @@ macro_rules! impl_gvec3 {
 		}
 	};
 }
-pub(crate) use impl_gvec3;
 
 impl_gvec3!(I8Vec3, I8Vec4, I8Vec2);
 impl_gvec3!(U8Vec3, U8Vec4, U8Vec2);
@@ -600,9 +1549,11 @@ where
 			F32Vec = Vec4,
 			F64Vec = DVec4,
 		> +
+		Vec4Swizzles<Vec2 = <Self::Truncated as GVec3>::Truncated, Vec3 = Self::Truncated> +
 		From<(Self::Scalar, Self::Scalar, Self::Scalar, Self::Scalar)> +
 		Into<(Self::Scalar, Self::Scalar, Self::Scalar, Self::Scalar)> +
 	,
+	Self::Truncated: GVec3 + GVec<Scalar = <Self as GVec>::Scalar>,
 {
 	type Truncated;
 	const X: Self;
@@ -642,7 +1593,6 @@ macro_rules! impl_gvec4 {
 		}
 	};
 }
-pub(crate) use impl_gvec4;
 
 impl_gvec4!(I8Vec4, I8Vec3);
 impl_gvec4!(U8Vec4, U8Vec3);
@@ -656,10 +1606,67 @@ impl_gvec4!(USizeVec4, USizeVec3);
 impl_gvec4!(Vec4, Vec3);
 impl_gvec4!(DVec4, DVec3);
 
+/**
+[`GVec2`] convertible to and from its `mint` types.
+*/
+#[cfg(feature = "mint")]
+pub trait MintVec2: GVec2 + From<Self::Mint> + Into<Self::Mint> + From<Self::MintPoint> + Into<Self::MintPoint> {
+	type Mint: From<Self> + Into<Self>;
+	type MintPoint: From<Self> + Into<Self>;
+}
+
+#[cfg(feature = "mint")]
+impl<V: GVec2> MintVec2 for V
+where
+	V: From<mint::Vector2<V::Scalar>> + From<mint::Point2<V::Scalar>>,
+	mint::Vector2<V::Scalar>: From<V>,
+	mint::Point2<V::Scalar>: From<V>,
+{
+	type Mint = mint::Vector2<V::Scalar>;
+	type MintPoint = mint::Point2<V::Scalar>;
+}
+
+/**
+[`GVec3`] convertible to and from its `mint` types.
+*/
+#[cfg(feature = "mint")]
+pub trait MintVec3: GVec3 + From<Self::Mint> + Into<Self::Mint> + From<Self::MintPoint> + Into<Self::MintPoint> {
+	type Mint: From<Self> + Into<Self>;
+	type MintPoint: From<Self> + Into<Self>;
+}
+
+#[cfg(feature = "mint")]
+impl<V: GVec3> MintVec3 for V
+where
+	V: From<mint::Vector3<V::Scalar>> + From<mint::Point3<V::Scalar>>,
+	mint::Vector3<V::Scalar>: From<V>,
+	mint::Point3<V::Scalar>: From<V>,
+{
+	type Mint = mint::Vector3<V::Scalar>;
+	type MintPoint = mint::Point3<V::Scalar>;
+}
+
+/**
+[`GVec4`] convertible to and from its `mint` type.
+*/
+#[cfg(feature = "mint")]
+pub trait MintVec4: GVec4 + From<Self::Mint> + Into<Self::Mint> {
+	type Mint: From<Self> + Into<Self>;
+}
+
+#[cfg(feature = "mint")]
+impl<V: GVec4> MintVec4 for V
+where
+	V: From<mint::Vector4<V::Scalar>>,
+	mint::Vector4<V::Scalar>: From<V>,
+{
+	type Mint = mint::Vector4<V::Scalar>;
+}
+
 /**
 Vector of any length whose elements are a signed type.
 */
-pub trait SignedVec: GVec + Neg {
+pub trait SignedVec: GVec<Scalar: SignedScalar> + Neg {
 	const NEG_ONE: Self;
 	fn abs(self) -> Self;
 	fn signum(self) -> Self;
@@ -682,7 +1689,6 @@ macro_rules! impl_signedvec {
 		}
 	};
 }
-pub(crate) use impl_signedvec;
 
 impl_signedvec!(I16Vec2);
 impl_signedvec!(I16Vec3);
@@ -726,7 +1732,6 @@ macro_rules! impl_signedvec2 {
 		}
 	};
 }
-pub(crate) use impl_signedvec2;
 
 impl_signedvec2!(I8Vec2);
 impl_signedvec2!(I16Vec2);
@@ -753,7 +1758,6 @@ macro_rules! impl_signedvec3 {
 		}
 	};
 }
-pub(crate) use impl_signedvec3;
 
 impl_signedvec3!(I8Vec3);
 impl_signedvec3!(I16Vec3);
@@ -783,7 +1787,6 @@ macro_rules! impl_signedvec4 {
 		}
 	};
 }
-pub(crate) use impl_signedvec4;
 
 impl_signedvec4!(I8Vec4);
 impl_signedvec4!(I16Vec4);
@@ -794,8 +1797,13 @@ impl_signedvec4!(DVec4);
 
 /**
 Vector of any length whose elements are a floating-point type.
+
+Where `glam` has no vector version, the transcendental functions apply the [`FloatScalar`] one to each element,
+so they use `libm` in the same builds.
+`step` is zero where `self` is below `edge` and one elsewhere, as in GLSL with the arguments swapped.
+Where `edge0` equals `edge1`, `smoothstep` divides by zero, so it is not NaN but a step: zero up to the edge and one above.
 */
-pub trait FloatVec: SignedVec {
+pub trait FloatVec: SignedVec<Scalar: FloatScalar> + Approx<<Self as GVec>::Scalar> {
 	const NAN: Self;
 	const INFINITY: Self;
 	const NEG_INFINITY: Self;
@@ -824,12 +1832,32 @@ pub trait FloatVec: SignedVec {
 	fn fract(self) -> Self;
 	fn fract_gl(self) -> Self;
 	fn exp(self) -> Self;
+	fn exp2(self) -> Self;
+	fn ln(self) -> Self;
+	fn log2(self) -> Self;
 	fn powf(self, n: Self::Scalar) -> Self;
+	fn sqrt(self) -> Self;
+	fn cbrt(self) -> Self;
+	fn hypot(self, rhs: Self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn tan(self) -> Self;
+	fn asin(self) -> Self;
+	fn acos(self) -> Self;
+	fn atan2(self, rhs: Self) -> Self;
+	fn sin_cos(self) -> (Self, Self);
 	fn recip(self) -> Self;
+	fn step(self, edge: Self) -> Self;
+	fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+	fn saturate(self) -> Self;
 	fn lerp(self, rhs: Self, s: Self::Scalar) -> Self;
 	fn move_towards(&self, rhs: Self, d: Self::Scalar) -> Self;
 	fn midpoint(self, rhs: Self) -> Self;
 	fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool;
+	#[cfg(feature = "approx")]
+	fn relative_eq(self, rhs: Self, epsilon: Self::Scalar, max_relative: Self::Scalar) -> bool;
+	#[cfg(feature = "approx")]
+	fn ulps_eq(self, rhs: Self, epsilon: Self::Scalar, max_ulps: u32) -> bool;
 	fn clamp_length(self, min: Self::Scalar, max: Self::Scalar) -> Self;
 	fn clamp_length_max(self, max: Self::Scalar) -> Self;
 	fn clamp_length_min(self, min: Self::Scalar) -> Self;
@@ -869,12 +1897,42 @@ macro_rules! impl_floatvec {
 			fn fract(self) -> Self { self.fract() }
 			fn fract_gl(self) -> Self { self.fract_gl() }
 			fn exp(self) -> Self { self.exp() }
+			fn exp2(self) -> Self { self.map(FloatScalar::exp2) }
+			fn ln(self) -> Self { self.map(FloatScalar::ln) }
+			fn log2(self) -> Self { self.map(FloatScalar::log2) }
 			fn powf(self, n: Self::Scalar) -> Self { self.powf(n) }
+			fn sqrt(self) -> Self { self.map(FloatScalar::sqrt) }
+			fn cbrt(self) -> Self { self.map(FloatScalar::cbrt) }
+			fn hypot(self, rhs: Self) -> Self { self.zip_map(rhs, FloatScalar::hypot) }
+			fn sin(self) -> Self { self.map(FloatScalar::sin) }
+			fn cos(self) -> Self { self.map(FloatScalar::cos) }
+			fn tan(self) -> Self { self.map(FloatScalar::tan) }
+			fn asin(self) -> Self { self.map(FloatScalar::asin) }
+			fn acos(self) -> Self { self.map(FloatScalar::acos) }
+			fn atan2(self, rhs: Self) -> Self { self.zip_map(rhs, FloatScalar::atan2) }
+			fn sin_cos(self) -> (Self, Self) {
+				let a = self.to_array().map(FloatScalar::sin_cos);
+				(Self::from_fn(|i| a[i].0), Self::from_fn(|i| a[i].1))
+			}
 			fn recip(self) -> Self { self.recip() }
+			fn step(self, edge: Self) -> Self { Self::select(self.cmplt(edge), Self::ZERO, Self::ONE) }
+			fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+				let t = FloatVec::saturate((self - edge0) / (edge1 - edge0));
+				t * t * (Self::splat(3.0) - t * 2.0)
+			}
+			fn saturate(self) -> Self { self.clamp(Self::ZERO, Self::ONE) }
 			fn lerp(self, rhs: Self, s: Self::Scalar) -> Self { self.lerp(rhs, s) }
 			fn move_towards(&self, rhs: Self, d: Self::Scalar) -> Self { self.move_towards(rhs, d) }
 			fn midpoint(self, rhs: Self) -> Self { self.midpoint(rhs) }
 			fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool { self.abs_diff_eq(rhs, max_abs_diff) }
+			#[cfg(feature = "approx")]
+			fn relative_eq(self, rhs: Self, epsilon: Self::Scalar, max_relative: Self::Scalar) -> bool {
+				approx::RelativeEq::relative_eq(&self, &rhs, epsilon, max_relative)
+			}
+			#[cfg(feature = "approx")]
+			fn ulps_eq(self, rhs: Self, epsilon: Self::Scalar, max_ulps: u32) -> bool {
+				approx::UlpsEq::ulps_eq(&self, &rhs, epsilon, max_ulps)
+			}
 			fn clamp_length(self, min: Self::Scalar, max: Self::Scalar) -> Self { self.clamp_length(min, max) }
 			fn clamp_length_max(self, max: Self::Scalar) -> Self { self.clamp_length_max(max) }
 			fn clamp_length_min(self, min: Self::Scalar) -> Self { self.clamp_length_min(min) }
@@ -896,7 +1954,17 @@ impl_floatvec!(DVec4);
 /**
 Vector of length 2 whose elements are a floating-point type.
 */
-pub trait FloatVec2: FloatVec + SignedVec2 {
+pub trait FloatVec2
+where
+	Self:
+		FloatVec +
+		SignedVec2 +
+		From<<Self::Affine as GAffine2>::Vec2> +
+		Into<<Self::Affine as GAffine2>::Vec2> +
+	,
+	Self::Affine: GAffine2 + GAffine<Scalar = <Self as GVec>::Scalar>,
+{
+	type Affine;
 	fn angle_to(self, rhs: Self) -> Self::Scalar;
 	fn from_angle(angle: Self::Scalar) -> Self;
 	fn to_angle(self) -> Self::Scalar;
@@ -904,8 +1972,9 @@ pub trait FloatVec2: FloatVec + SignedVec2 {
 }
 
 macro_rules! impl_floatvec2 {
-	($type:ty) => {
+	($type:ty, $affine:ty) => {
 		impl FloatVec2 for $type {
+			type Affine = $affine;
 			fn angle_to(self, rhs: Self) -> Self::Scalar { self.angle_to(rhs) }
 			fn from_angle(angle: Self::Scalar) -> Self { Self::from_angle(angle) }
 			fn to_angle(self) -> Self::Scalar { self.to_angle() }
@@ -914,13 +1983,27 @@ macro_rules! impl_floatvec2 {
 	};
 }
 
-impl_floatvec2!(Vec2);
-impl_floatvec2!(DVec2);
+impl_floatvec2!(Vec2, Affine2);
+impl_floatvec2!(DVec2, DAffine2);
 
 /**
 Vector of length 3 whose elements are a floating-point type.
 */
-pub trait FloatVec3: FloatVec + SignedVec3 {
+pub trait FloatVec3
+where
+	Self:
+		FloatVec +
+		SignedVec3 +
+		From<<Self::Quat as GQuat>::Vec3> +
+		Into<<Self::Quat as GQuat>::Vec3> +
+		From<<Self::Affine as GAffine3>::Vec3> +
+		Into<<Self::Affine as GAffine3>::Vec3> +
+	,
+	Self::Quat: GQuat<Scalar = <Self as GVec>::Scalar> + Mul<Self, Output = Self>,
+	Self::Affine: GAffine3<Quat = Self::Quat> + GAffine<Scalar = <Self as GVec>::Scalar>,
+{
+	type Quat;
+	type Affine;
 	fn angle_between(self, rhs: Self) -> Self::Scalar;
 	fn any_orthogonal_vector(&self) -> Self;
 	fn any_orthonormal_vector(&self) -> Self;
@@ -930,8 +2013,10 @@ pub trait FloatVec3: FloatVec + SignedVec3 {
 }
 
 macro_rules! impl_floatvec3 {
-	($type:ty) => {
+	($type:ty, $quat:ty, $affine:ty) => {
 		impl FloatVec3 for $type {
+			type Quat = $quat;
+			type Affine = $affine;
 			fn angle_between(self, rhs: Self) -> Self::Scalar { self.angle_between(rhs) }
 			fn any_orthogonal_vector(&self) -> Self { self.any_orthogonal_vector() }
 			fn any_orthonormal_vector(&self) -> Self { self.any_orthonormal_vector() }
@@ -942,9 +2027,9 @@ macro_rules! impl_floatvec3 {
 	};
 }
 
-impl_floatvec3!(Vec3);
-impl_floatvec3!(Vec3A);
-impl_floatvec3!(DVec3);
+impl_floatvec3!(Vec3, Quat, Affine3A);
+impl_floatvec3!(Vec3A, Quat, Affine3A);
+impl_floatvec3!(DVec3, DQuat, DAffine3);
 
 /**
 Vector of length 4 whose elements are a floating-point type.
@@ -954,13 +2039,119 @@ pub trait FloatVec4: FloatVec + SignedVec4 {}
 impl FloatVec4 for Vec4 {}
 impl FloatVec4 for DVec4 {}
 
+/**
+[`GVec`] whose elements can be sampled by `rand`.
+
+`random_range` samples each element uniformly from `min[i]..=max[i]`.
+
+# Panics
+
+`random_range` panics if any element of `min` is greater than that of `max`, or either is not finite.
+*/
+#[cfg(feature = "rand")]
+pub trait RandomVec: GVec {
+	fn random_range<R: rand::Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self;
+}
+
+#[cfg(feature = "rand")]
+impl<V: GVec> RandomVec for V
+where
+	V::Scalar: rand::distr::uniform::SampleUniform,
+{
+	fn random_range<R: rand::Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self {
+		min.zip_map(max, |min, max| rng.random_range(min..=max))
+	}
+}
+
+/**
+[`FloatVec2`] with uniform sampling of the unit circle and disk.
+*/
+#[cfg(feature = "rand")]
+pub trait RandomFloatVec2: FloatVec2 {
+	fn random_on_unit_circle<R: rand::Rng + ?Sized>(rng: &mut R) -> Self;
+	fn random_in_unit_disk<R: rand::Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+#[cfg(feature = "rand")]
+impl<V: FloatVec2> RandomFloatVec2 for V
+where
+	V::Scalar: rand::distr::uniform::SampleUniform,
+	rand::distr::StandardUniform: rand::distr::Distribution<V::Scalar>,
+{
+	fn random_on_unit_circle<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		Self::from_angle(rng.random_range(V::Scalar::ZERO..V::Scalar::TAU))
+	}
+	fn random_in_unit_disk<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		Self::random_on_unit_circle(rng) * rng.random::<V::Scalar>().sqrt()
+	}
+}
+
+/**
+[`FloatVec3`] with uniform sampling of the unit sphere, ball and hemispheres.
+*/
+#[cfg(feature = "rand")]
+pub trait RandomFloatVec3: FloatVec3 {
+	fn random_on_unit_sphere<R: rand::Rng + ?Sized>(rng: &mut R) -> Self;
+	fn random_in_unit_ball<R: rand::Rng + ?Sized>(rng: &mut R) -> Self;
+	fn random_on_hemisphere<R: rand::Rng + ?Sized>(rng: &mut R, normal: Self) -> Self;
+}
+
+#[cfg(feature = "rand")]
+impl<V: FloatVec3> RandomFloatVec3 for V
+where
+	V::Scalar: rand::distr::uniform::SampleUniform,
+	rand::distr::StandardUniform: rand::distr::Distribution<V::Scalar>,
+{
+	fn random_on_unit_sphere<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		let z = rng.random_range(-V::Scalar::ONE..=V::Scalar::ONE);
+		let (sin, cos) = rng.random_range(V::Scalar::ZERO..V::Scalar::TAU).sin_cos();
+		let r = (V::Scalar::ONE - z * z).sqrt();
+		Self::new(r * cos, r * sin, z)
+	}
+	fn random_in_unit_ball<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+		Self::random_on_unit_sphere(rng) * rng.random::<V::Scalar>().cbrt()
+	}
+	fn random_on_hemisphere<R: rand::Rng + ?Sized>(rng: &mut R, normal: Self) -> Self {
+		let v = Self::random_on_unit_sphere(rng);
+		if v.dot(normal) < V::Scalar::ZERO { v * V::NEG_ONE } else { v }
+	}
+}
+
+/**
+[`FloatVec`] whose scalar is a `num_traits::Float`.
+*/
+#[cfg(feature = "num-traits")]
+pub trait NumFloatVec: FloatVec<Scalar: num_traits::Float> {}
+
+#[cfg(feature = "num-traits")]
+impl<V: FloatVec<Scalar: num_traits::Float>> NumFloatVec for V {}
+
 /**
 Vector of any length whose elements are an integer type.
+
+`Unsigned` and `Signed` are the vectors of the same length and width with the other signedness, or `Self`.
+`glam` has no signed `usize` vectors, so the `Signed` of a [`USizeVec`] is an `I64Vec`.
+
+`div_floor` and `div_ceil` round toward negative and positive infinity, and `rem_floor` takes the sign of `rhs`.
+`align_down` and `round_up_to_multiple` round to a multiple of the magnitude of `rhs` in the same directions, whatever its
+sign, and wrap where that multiple doesn't fit, as for rounding `u8::MAX` up to a multiple of 16.
+`gcd` and `lcm` are non-negative, except that they wrap where the result doesn't fit, as for `gcd(MIN, 0)` or the `lcm` of
+two large coprime elements.
+
+# Panics
+
+`ilog2` panics if any element is zero or negative, where `checked_ilog2` returns `None`.
+
+`div_floor`, `div_ceil`, `rem_floor`, `align_down` and `round_up_to_multiple` panic if any element of `rhs` is zero.
+`div_floor`, `div_ceil` and `rem_floor` also panic on the overflow of dividing `MIN` by `-1`.
+
+`pow` overflows like the scalar `pow`, panicking with debug assertions and wrapping without them.
+`checked_pow`, `wrapping_pow` and `saturating_pow` define the result on overflow.
 */
 pub trait IntVec
 where
 	Self:
-		GVec +
+		GVec<Scalar: IntScalar> +
 		Eq +
 		Not<Output = Self> +
 		BitAnd<Output = Self> +
@@ -986,76 +2177,235 @@ where
 		Shl<u64, Output = Self> +
 		Shr<u64, Output = Self> +
 	,
+	Self::UnsignedScalar: UIntScalar,
+	Self::Unsigned: UIntVec + GVec<Scalar = Self::UnsignedScalar, BVec = <Self as GVec>::BVec>,
+	Self::Signed: SIntVec + GVec<BVec = <Self as GVec>::BVec>,
 {
 	type UnsignedScalar;
+	type Unsigned;
+	type Signed;
 	fn checked_add(self, rhs: Self) -> Option<Self>;
 	fn checked_sub(self, rhs: Self) -> Option<Self>;
 	fn checked_mul(self, rhs: Self) -> Option<Self>;
 	fn checked_div(self, rhs: Self) -> Option<Self>;
+	fn checked_rem(self, rhs: Self) -> Option<Self>;
+	fn checked_neg(self) -> Option<Self>;
+	fn checked_pow(self, exp: u32) -> Option<Self>;
 	fn wrapping_add(self, rhs: Self) -> Self;
 	fn wrapping_sub(self, rhs: Self) -> Self;
 	fn wrapping_mul(self, rhs: Self) -> Self;
 	fn wrapping_div(self, rhs: Self) -> Self;
+	fn wrapping_rem(self, rhs: Self) -> Self;
+	fn wrapping_neg(self) -> Self;
+	fn wrapping_pow(self, exp: u32) -> Self;
 	fn saturating_add(self, rhs: Self) -> Self;
 	fn saturating_sub(self, rhs: Self) -> Self;
 	fn saturating_mul(self, rhs: Self) -> Self;
 	fn saturating_div(self, rhs: Self) -> Self;
+	fn saturating_pow(self, exp: u32) -> Self;
+	fn pow(self, exp: u32) -> Self;
+	fn abs_diff(self, rhs: Self) -> Self::Unsigned;
+	fn count_ones(self) -> Self::U32Vec;
+	fn count_zeros(self) -> Self::U32Vec;
+	fn leading_zeros(self) -> Self::U32Vec;
+	fn trailing_zeros(self) -> Self::U32Vec;
+	fn ilog2(self) -> Self::U32Vec;
+	fn checked_ilog2(self) -> Option<Self::U32Vec>;
+	fn rotate_left(self, n: u32) -> Self;
+	fn rotate_right(self, n: u32) -> Self;
+	fn swap_bytes(self) -> Self;
+	fn reverse_bits(self) -> Self;
+	fn div_floor(self, rhs: Self) -> Self;
+	fn div_ceil(self, rhs: Self) -> Self;
+	fn rem_floor(self, rhs: Self) -> Self;
+	fn round_up_to_multiple(self, rhs: Self) -> Self;
+	fn align_down(self, rhs: Self) -> Self;
+	fn gcd(self, rhs: Self) -> Self;
+	fn lcm(self, rhs: Self) -> Self;
 	fn manhattan_distance(self, rhs: Self) -> Self::UnsignedScalar;
 	fn checked_manhattan_distance(self, rhs: Self) -> Option<Self::UnsignedScalar>;
 	fn chebyshev_distance(self, rhs: Self) -> Self::UnsignedScalar;
 }
 
+/**
+Builds a vector from fallible components, short-circuiting on the first `None`.
+*/
+fn try_from_fn<V: GVec>(mut f: impl FnMut(usize) -> Option<V::Scalar>) -> Option<V> {
+	let mut out = V::ZERO;
+	for i in 0..V::DIM {
+		out[i] = f(i)?;
+	}
+	Some(out)
+}
+
+/**
+Division rounded toward negative infinity, for signed and unsigned scalars alike.
+*/
+fn div_floor<S: IntScalar>(a: S, b: S) -> S {
+	let q = a / b;
+	if a % b != S::ZERO && (a < S::ZERO) != (b < S::ZERO) { q - S::ONE } else { q }
+}
+
+/**
+Division rounded toward positive infinity, for signed and unsigned scalars alike.
+*/
+fn div_ceil<S: IntScalar>(a: S, b: S) -> S {
+	let q = a / b;
+	if a % b != S::ZERO && (a < S::ZERO) == (b < S::ZERO) { q + S::ONE } else { q }
+}
+
+/**
+Remainder of [`div_floor`], which takes the sign of the divisor.
+*/
+fn rem_floor<S: IntScalar>(a: S, b: S) -> S {
+	let r = a % b;
+	if r != S::ZERO && (r < S::ZERO) != (b < S::ZERO) { r + b } else { r }
+}
+
+/**
+Largest multiple of the magnitude of `b` not above `a`, wrapping where it is below `MIN`.
+*/
+fn align_down<S: IntScalar>(a: S, b: S) -> S {
+	let r = a.wrapping_rem(b);
+	let q = a - r;
+	if r >= S::ZERO {
+		q
+	} else if b < S::ZERO {
+		q.wrapping_add(b)
+	} else {
+		q.wrapping_sub(b)
+	}
+}
+
+/**
+Smallest multiple of the magnitude of `b` not below `a`, wrapping where it is above `MAX`.
+*/
+fn round_up_to_multiple<S: IntScalar>(a: S, b: S) -> S {
+	let r = a.wrapping_rem(b);
+	let q = a - r;
+	if r <= S::ZERO {
+		q
+	} else if b < S::ZERO {
+		q.wrapping_sub(b)
+	} else {
+		q.wrapping_add(b)
+	}
+}
+
+/**
+Greatest common divisor, made non-negative with wrapping so that `gcd(MIN, 0)` is `MIN` rather than an overflow.
+*/
+fn gcd<S: IntScalar>(mut a: S, mut b: S) -> S {
+	while b != S::ZERO {
+		(a, b) = (b, a.wrapping_rem(b));
+	}
+	if a < S::ZERO { a.wrapping_neg() } else { a }
+}
+
+/**
+Least common multiple, wrapping where it is out of range, and zero if either input is.
+*/
+fn lcm<S: IntScalar>(a: S, b: S) -> S {
+	if a == S::ZERO || b == S::ZERO {
+		return S::ZERO;
+	}
+	let l = (a / gcd(a, b)).wrapping_mul(b);
+	if l < S::ZERO { l.wrapping_neg() } else { l }
+}
+
 macro_rules! impl_intvec {
-	($type:ty, $unsigned:ty) => {
+	($type:ty, $unsigned_scalar:ty, $unsigned:ty, $signed:ty) => {
 		impl IntVec for $type {
-			type UnsignedScalar = $unsigned;
+			type UnsignedScalar = $unsigned_scalar;
+			type Unsigned = $unsigned;
+			type Signed = $signed;
 			fn checked_add(self, rhs: Self) -> Option<Self> { self.checked_add(rhs) }
 			fn checked_sub(self, rhs: Self) -> Option<Self> { self.checked_sub(rhs) }
 			fn checked_mul(self, rhs: Self) -> Option<Self> { self.checked_mul(rhs) }
 			fn checked_div(self, rhs: Self) -> Option<Self> { self.checked_div(rhs) }
+			fn checked_rem(self, rhs: Self) -> Option<Self> {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				try_from_fn(|i| a[i].checked_rem(b[i]))
+			}
+			fn checked_neg(self) -> Option<Self> {
+				let a = self.to_array();
+				try_from_fn(|i| a[i].checked_neg())
+			}
+			fn checked_pow(self, exp: u32) -> Option<Self> {
+				let a = self.to_array();
+				try_from_fn(|i| a[i].checked_pow(exp))
+			}
 			fn wrapping_add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
 			fn wrapping_sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
 			fn wrapping_mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
 			fn wrapping_div(self, rhs: Self) -> Self { self.wrapping_div(rhs) }
+			fn wrapping_rem(self, rhs: Self) -> Self { self.zip_map(rhs, |a, b| a.wrapping_rem(b)) }
+			fn wrapping_neg(self) -> Self { self.map(|v| v.wrapping_neg()) }
+			fn wrapping_pow(self, exp: u32) -> Self { self.map(|v| v.wrapping_pow(exp)) }
 			fn saturating_add(self, rhs: Self) -> Self { self.saturating_add(rhs) }
 			fn saturating_sub(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
 			fn saturating_mul(self, rhs: Self) -> Self { self.saturating_mul(rhs) }
 			fn saturating_div(self, rhs: Self) -> Self { self.saturating_div(rhs) }
+			fn saturating_pow(self, exp: u32) -> Self { self.map(|v| v.saturating_pow(exp)) }
+			fn pow(self, exp: u32) -> Self { self.map(|v| v.pow(exp)) }
+			fn abs_diff(self, rhs: Self) -> Self::Unsigned {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				Self::Unsigned::from_array(core::array::from_fn(|i| a[i].abs_diff(b[i])))
+			}
+			fn count_ones(self) -> Self::U32Vec { self.map_to(|v| v.count_ones()) }
+			fn count_zeros(self) -> Self::U32Vec { self.map_to(|v| v.count_zeros()) }
+			fn leading_zeros(self) -> Self::U32Vec { self.map_to(|v| v.leading_zeros()) }
+			fn trailing_zeros(self) -> Self::U32Vec { self.map_to(|v| v.trailing_zeros()) }
+			fn ilog2(self) -> Self::U32Vec { self.map_to(|v| v.ilog2()) }
+			fn checked_ilog2(self) -> Option<Self::U32Vec> {
+				let a = self.to_array();
+				try_from_fn(|i| a[i].checked_ilog2())
+			}
+			fn rotate_left(self, n: u32) -> Self { self.map(|v| v.rotate_left(n)) }
+			fn rotate_right(self, n: u32) -> Self { self.map(|v| v.rotate_right(n)) }
+			fn swap_bytes(self) -> Self { self.map(|v| v.swap_bytes()) }
+			fn reverse_bits(self) -> Self { self.map(|v| v.reverse_bits()) }
+			fn div_floor(self, rhs: Self) -> Self { self.zip_map(rhs, div_floor) }
+			fn div_ceil(self, rhs: Self) -> Self { self.zip_map(rhs, div_ceil) }
+			fn rem_floor(self, rhs: Self) -> Self { self.zip_map(rhs, rem_floor) }
+			fn round_up_to_multiple(self, rhs: Self) -> Self { self.zip_map(rhs, round_up_to_multiple) }
+			fn align_down(self, rhs: Self) -> Self { self.zip_map(rhs, align_down) }
+			fn gcd(self, rhs: Self) -> Self { self.zip_map(rhs, gcd) }
+			fn lcm(self, rhs: Self) -> Self { self.zip_map(rhs, lcm) }
 			fn manhattan_distance(self, rhs: Self) -> Self::UnsignedScalar { self.manhattan_distance(rhs) }
 			fn checked_manhattan_distance(self, rhs: Self) -> Option<Self::UnsignedScalar> { self.checked_manhattan_distance(rhs) }
 			fn chebyshev_distance(self, rhs: Self) -> Self::UnsignedScalar { self.chebyshev_distance(rhs) }
 		}
 	};
 }
-pub(crate) use impl_intvec;
-
-impl_intvec!(I8Vec2, u8);
-impl_intvec!(I8Vec3, u8);
-impl_intvec!(I8Vec4, u8);
-impl_intvec!(U8Vec2, u8);
-impl_intvec!(U8Vec3, u8);
-impl_intvec!(U8Vec4, u8);
-impl_intvec!(I16Vec2, u16);
-impl_intvec!(I16Vec3, u16);
-impl_intvec!(I16Vec4, u16);
-impl_intvec!(U16Vec2, u16);
-impl_intvec!(U16Vec3, u16);
-impl_intvec!(U16Vec4, u16);
-impl_intvec!(IVec2, u32);
-impl_intvec!(IVec3, u32);
-impl_intvec!(IVec4, u32);
-impl_intvec!(UVec2, u32);
-impl_intvec!(UVec3, u32);
-impl_intvec!(UVec4, u32);
-impl_intvec!(I64Vec2, u64);
-impl_intvec!(I64Vec3, u64);
-impl_intvec!(I64Vec4, u64);
-impl_intvec!(U64Vec2, u64);
-impl_intvec!(U64Vec3, u64);
-impl_intvec!(U64Vec4, u64);
-impl_intvec!(USizeVec2, usize);
-impl_intvec!(USizeVec3, usize);
-impl_intvec!(USizeVec4, usize);
+
+impl_intvec!(I8Vec2, u8, U8Vec2, I8Vec2);
+impl_intvec!(I8Vec3, u8, U8Vec3, I8Vec3);
+impl_intvec!(I8Vec4, u8, U8Vec4, I8Vec4);
+impl_intvec!(U8Vec2, u8, U8Vec2, I8Vec2);
+impl_intvec!(U8Vec3, u8, U8Vec3, I8Vec3);
+impl_intvec!(U8Vec4, u8, U8Vec4, I8Vec4);
+impl_intvec!(I16Vec2, u16, U16Vec2, I16Vec2);
+impl_intvec!(I16Vec3, u16, U16Vec3, I16Vec3);
+impl_intvec!(I16Vec4, u16, U16Vec4, I16Vec4);
+impl_intvec!(U16Vec2, u16, U16Vec2, I16Vec2);
+impl_intvec!(U16Vec3, u16, U16Vec3, I16Vec3);
+impl_intvec!(U16Vec4, u16, U16Vec4, I16Vec4);
+impl_intvec!(IVec2, u32, UVec2, IVec2);
+impl_intvec!(IVec3, u32, UVec3, IVec3);
+impl_intvec!(IVec4, u32, UVec4, IVec4);
+impl_intvec!(UVec2, u32, UVec2, IVec2);
+impl_intvec!(UVec3, u32, UVec3, IVec3);
+impl_intvec!(UVec4, u32, UVec4, IVec4);
+impl_intvec!(I64Vec2, u64, U64Vec2, I64Vec2);
+impl_intvec!(I64Vec3, u64, U64Vec3, I64Vec3);
+impl_intvec!(I64Vec4, u64, U64Vec4, I64Vec4);
+impl_intvec!(U64Vec2, u64, U64Vec2, I64Vec2);
+impl_intvec!(U64Vec3, u64, U64Vec3, I64Vec3);
+impl_intvec!(U64Vec4, u64, U64Vec4, I64Vec4);
+impl_intvec!(USizeVec2, usize, USizeVec2, I64Vec2);
+impl_intvec!(USizeVec3, usize, USizeVec3, I64Vec3);
+impl_intvec!(USizeVec4, usize, USizeVec4, I64Vec4);
 
 /**
 Vector of length 2 whose elements are an integer type.
@@ -1135,20 +2485,42 @@ impl IntVec4 for USizeVec4 {}
 /**
 Vector of any length whose elements are a signed integer type.
 */
-pub trait SIntVec: IntVec + SignedVec {}
+pub trait SIntVec: IntVec + SignedVec<Scalar: SIntScalar> {
+	fn unsigned_abs(self) -> Self::Unsigned;
+	fn checked_add_unsigned(self, rhs: Self::Unsigned) -> Option<Self>;
+	fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self>;
+	fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+	fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+	fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self;
+	fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self;
+}
+
+macro_rules! impl_sintvec {
+	($type:ty) => {
+		impl SIntVec for $type {
+			fn unsigned_abs(self) -> Self::Unsigned { Self::Unsigned::from_array(self.to_array().map(|v| v.unsigned_abs())) }
+			fn checked_add_unsigned(self, rhs: Self::Unsigned) -> Option<Self> { self.checked_add_unsigned(rhs) }
+			fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> Option<Self> { self.checked_sub_unsigned(rhs) }
+			fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self { self.wrapping_add_unsigned(rhs) }
+			fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self { self.wrapping_sub_unsigned(rhs) }
+			fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self { self.saturating_add_unsigned(rhs) }
+			fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self { self.saturating_sub_unsigned(rhs) }
+		}
+	};
+}
 
-impl SIntVec for I8Vec2 {}
-impl SIntVec for I8Vec3 {}
-impl SIntVec for I8Vec4 {}
-impl SIntVec for I16Vec2 {}
-impl SIntVec for I16Vec3 {}
-impl SIntVec for I16Vec4 {}
-impl SIntVec for IVec2 {}
-impl SIntVec for IVec3 {}
-impl SIntVec for IVec4 {}
-impl SIntVec for I64Vec2 {}
-impl SIntVec for I64Vec3 {}
-impl SIntVec for I64Vec4 {}
+impl_sintvec!(I8Vec2);
+impl_sintvec!(I8Vec3);
+impl_sintvec!(I8Vec4);
+impl_sintvec!(I16Vec2);
+impl_sintvec!(I16Vec3);
+impl_sintvec!(I16Vec4);
+impl_sintvec!(IVec2);
+impl_sintvec!(IVec3);
+impl_sintvec!(IVec4);
+impl_sintvec!(I64Vec2);
+impl_sintvec!(I64Vec3);
+impl_sintvec!(I64Vec4);
 
 /**
 Vector of length 2 whose elements are a signed integer type.
@@ -1183,23 +2555,66 @@ impl SIntVec4 for I64Vec4 {}
 /**
 Vector of any length whose elements are an unsigned integer type.
 */
-pub trait UIntVec: IntVec {}
-
-impl UIntVec for U8Vec2 {}
-impl UIntVec for U8Vec3 {}
-impl UIntVec for U8Vec4 {}
-impl UIntVec for U16Vec2 {}
-impl UIntVec for U16Vec3 {}
-impl UIntVec for U16Vec4 {}
-impl UIntVec for UVec2 {}
-impl UIntVec for UVec3 {}
-impl UIntVec for UVec4 {}
-impl UIntVec for U64Vec2 {}
-impl UIntVec for U64Vec3 {}
-impl UIntVec for U64Vec4 {}
-impl UIntVec for USizeVec2 {}
-impl UIntVec for USizeVec3 {}
-impl UIntVec for USizeVec4 {}
+pub trait UIntVec: IntVec<Scalar: UIntScalar> {
+	fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self>;
+	fn wrapping_add_signed(self, rhs: Self::Signed) -> Self;
+	fn saturating_add_signed(self, rhs: Self::Signed) -> Self;
+	fn div_euclid(self, rhs: Self) -> Self;
+	fn rem_euclid(self, rhs: Self) -> Self;
+	fn is_power_of_two(self) -> Self::BVec;
+	fn next_power_of_two(self) -> Self;
+}
+
+macro_rules! impl_uintvec {
+	($type:ty) => {
+		impl UIntVec for $type {
+			fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self> { self.checked_add_signed(rhs) }
+			fn wrapping_add_signed(self, rhs: Self::Signed) -> Self { self.wrapping_add_signed(rhs) }
+			fn saturating_add_signed(self, rhs: Self::Signed) -> Self { self.saturating_add_signed(rhs) }
+			fn div_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, |a, b| a.div_euclid(b)) }
+			fn rem_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, |a, b| a.rem_euclid(b)) }
+			fn is_power_of_two(self) -> Self::BVec { Self::BVec::from_array(self.to_array().map(|v| v.is_power_of_two())) }
+			fn next_power_of_two(self) -> Self { self.map(|v| v.next_power_of_two()) }
+		}
+	};
+	// `glam` has no signed `usize` vectors, so these go through `u64` with an `I64Vec` offset.
+	(usize, $type:ty) => {
+		impl UIntVec for $type {
+			fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self> {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				try_from_fn(|i| usize::try_from((a[i] as u64).checked_add_signed(b[i])?).ok())
+			}
+			fn wrapping_add_signed(self, rhs: Self::Signed) -> Self {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				Self::from_fn(|i| (a[i] as u64).wrapping_add_signed(b[i]) as usize)
+			}
+			fn saturating_add_signed(self, rhs: Self::Signed) -> Self {
+				let (a, b) = (self.to_array(), rhs.to_array());
+				Self::from_fn(|i| usize::try_from((a[i] as u64).saturating_add_signed(b[i])).unwrap_or(usize::MAX))
+			}
+			fn div_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, |a, b| a.div_euclid(b)) }
+			fn rem_euclid(self, rhs: Self) -> Self { self.zip_map(rhs, |a, b| a.rem_euclid(b)) }
+			fn is_power_of_two(self) -> Self::BVec { Self::BVec::from_array(self.to_array().map(|v| v.is_power_of_two())) }
+			fn next_power_of_two(self) -> Self { self.map(|v| v.next_power_of_two()) }
+		}
+	};
+}
+
+impl_uintvec!(U8Vec2);
+impl_uintvec!(U8Vec3);
+impl_uintvec!(U8Vec4);
+impl_uintvec!(U16Vec2);
+impl_uintvec!(U16Vec3);
+impl_uintvec!(U16Vec4);
+impl_uintvec!(UVec2);
+impl_uintvec!(UVec3);
+impl_uintvec!(UVec4);
+impl_uintvec!(U64Vec2);
+impl_uintvec!(U64Vec3);
+impl_uintvec!(U64Vec4);
+impl_uintvec!(usize, USizeVec2);
+impl_uintvec!(usize, USizeVec3);
+impl_uintvec!(usize, USizeVec4);
 
 /**
 Vector of length 2 whose elements are an unsigned integer type.
@@ -1234,6 +2649,33 @@ impl UIntVec4 for UVec4 {}
 impl UIntVec4 for U64Vec4 {}
 impl UIntVec4 for USizeVec4 {}
 
+/**
+[`IntVec`] whose scalar is a `num_traits::PrimInt`.
+*/
+#[cfg(feature = "num-traits")]
+pub trait NumIntVec: IntVec<Scalar: num_traits::PrimInt> {}
+
+#[cfg(feature = "num-traits")]
+impl<V: IntVec<Scalar: num_traits::PrimInt>> NumIntVec for V {}
+
+/**
+[`SIntVec`] whose scalar is a `num_traits::PrimInt` and `num_traits::Signed`.
+*/
+#[cfg(feature = "num-traits")]
+pub trait NumSIntVec: SIntVec<Scalar: num_traits::PrimInt + num_traits::Signed> + NumIntVec {}
+
+#[cfg(feature = "num-traits")]
+impl<V: SIntVec<Scalar: num_traits::PrimInt + num_traits::Signed>> NumSIntVec for V {}
+
+/**
+[`UIntVec`] whose scalar is a `num_traits::PrimInt` and `num_traits::Unsigned`.
+*/
+#[cfg(feature = "num-traits")]
+pub trait NumUIntVec: UIntVec<Scalar: num_traits::PrimInt + num_traits::Unsigned> + NumIntVec {}
+
+#[cfg(feature = "num-traits")]
+impl<V: UIntVec<Scalar: num_traits::PrimInt + num_traits::Unsigned>> NumUIntVec for V {}
+
 /**
 Vector of any length whose elements are [`i8`].
 */
@@ -1333,3 +2775,763 @@ pub trait F64Vec: FloatVec<Scalar = f64> {}
 impl F64Vec for DVec2 {}
 impl F64Vec for DVec3 {}
 impl F64Vec for DVec4 {}
+
+/**
+Generic matrix of any size.
+*/
+pub trait GMat
+where
+	for <'a> Self:
+		Sealed +
+		Serde +
+		Approx<Self::Scalar> +
+		Clone +
+		Copy +
+		PartialEq +
+		Default +
+		Display +
+		Debug +
+		Add<Output = Self> +
+		Add<&'a Self, Output = Self> +
+		AddAssign +
+		AddAssign<&'a Self> +
+		Sub<Output = Self> +
+		Sub<&'a Self, Output = Self> +
+		SubAssign +
+		SubAssign<&'a Self> +
+		Mul<Output = Self> +
+		Mul<&'a Self, Output = Self> +
+		Mul<Self::Scalar, Output = Self> +
+		Mul<&'a Self::Scalar, Output = Self> +
+		Mul<Self::Col, Output = Self::Col> +
+		Mul<&'a Self::Col, Output = Self::Col> +
+		MulAssign +
+		MulAssign<&'a Self> +
+		MulAssign<Self::Scalar> +
+		MulAssign<&'a Self::Scalar> +
+		Div<Self::Scalar, Output = Self> +
+		Div<&'a Self::Scalar, Output = Self> +
+		DivAssign<Self::Scalar> +
+		DivAssign<&'a Self::Scalar> +
+		Neg<Output = Self> +
+		Sum +
+		Sum<&'a Self> +
+		Product +
+		Product<&'a Self> +
+	,
+	for <'a> Self::Scalar:
+		FloatScalar +
+		Mul<Self, Output = Self> +
+		Mul<&'a Self, Output = Self> +
+		Div<Self, Output = Self> +
+		Div<&'a Self, Output = Self> +
+	,
+	Self::Col: FloatVec<Scalar = Self::Scalar>,
+	Self::Array: Index<usize, Output = Self::Scalar>,
+	Self::Array2d: Index<usize, Output = <Self::Col as GVec>::Array>,
+{
+	type Scalar;
+	type Col;
+	type Array;
+	type Array2d;
+	const ZERO: Self;
+	const IDENTITY: Self;
+	const NAN: Self;
+	const DIM: usize;
+	fn from_cols_array(m: &Self::Array) -> Self;
+	fn to_cols_array(&self) -> Self::Array;
+	fn from_cols_array_2d(m: &Self::Array2d) -> Self;
+	fn to_cols_array_2d(&self) -> Self::Array2d;
+	fn from_cols_slice(slice: &[Self::Scalar]) -> Self;
+	fn write_cols_to_slice(self, slice: &mut [Self::Scalar]);
+	fn from_diagonal(diagonal: Self::Col) -> Self;
+	fn col(&self, index: usize) -> Self::Col;
+	fn col_mut(&mut self, index: usize) -> &mut Self::Col;
+	fn row(&self, index: usize) -> Self::Col;
+	fn is_finite(&self) -> bool;
+	fn is_nan(&self) -> bool;
+	fn transpose(&self) -> Self;
+	fn determinant(&self) -> Self::Scalar;
+	fn inverse(&self) -> Self;
+	fn mul_vec(&self, rhs: Self::Col) -> Self::Col;
+	fn mul_mat(&self, rhs: &Self) -> Self;
+	fn add_mat(&self, rhs: &Self) -> Self;
+	fn sub_mat(&self, rhs: &Self) -> Self;
+	fn mul_scalar(&self, rhs: Self::Scalar) -> Self;
+	fn div_scalar(&self, rhs: Self::Scalar) -> Self;
+	fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool;
+	fn abs(&self) -> Self;
+}
+
+macro_rules! impl_gmat {
+	($type:ty, $scalar:ty, $col:ty, $dim:literal, $mul_vec:ident, $mul_mat:ident, $add_mat:ident, $sub_mat:ident) => {
+		impl Sealed for $type {}
+		impl GMat for $type {
+			type Scalar = $scalar;
+			type Col = $col;
+			type Array = [$scalar; $dim * $dim];
+			type Array2d = [[$scalar; $dim]; $dim];
+			const ZERO: Self = Self::ZERO;
+			const IDENTITY: Self = Self::IDENTITY;
+			const NAN: Self = Self::NAN;
+			const DIM: usize = $dim;
+			fn from_cols_array(m: &Self::Array) -> Self { Self::from_cols_array(m) }
+			fn to_cols_array(&self) -> Self::Array { self.to_cols_array() }
+			fn from_cols_array_2d(m: &Self::Array2d) -> Self { Self::from_cols_array_2d(m) }
+			fn to_cols_array_2d(&self) -> Self::Array2d { self.to_cols_array_2d() }
+			fn from_cols_slice(slice: &[Self::Scalar]) -> Self { Self::from_cols_slice(slice) }
+			fn write_cols_to_slice(self, slice: &mut [Self::Scalar]) { self.write_cols_to_slice(slice) }
+			fn from_diagonal(diagonal: Self::Col) -> Self { Self::from_diagonal(diagonal.into()) }
+			fn col(&self, index: usize) -> Self::Col { self.col(index) }
+			fn col_mut(&mut self, index: usize) -> &mut Self::Col { self.col_mut(index) }
+			fn row(&self, index: usize) -> Self::Col { self.row(index) }
+			fn is_finite(&self) -> bool { self.is_finite() }
+			fn is_nan(&self) -> bool { self.is_nan() }
+			fn transpose(&self) -> Self { self.transpose() }
+			fn determinant(&self) -> Self::Scalar { self.determinant() }
+			fn inverse(&self) -> Self { self.inverse() }
+			fn mul_vec(&self, rhs: Self::Col) -> Self::Col { self.$mul_vec(rhs) }
+			fn mul_mat(&self, rhs: &Self) -> Self { self.$mul_mat(rhs) }
+			fn add_mat(&self, rhs: &Self) -> Self { self.$add_mat(rhs) }
+			fn sub_mat(&self, rhs: &Self) -> Self { self.$sub_mat(rhs) }
+			fn mul_scalar(&self, rhs: Self::Scalar) -> Self { self.mul_scalar(rhs) }
+			fn div_scalar(&self, rhs: Self::Scalar) -> Self { self.div_scalar(rhs) }
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool { Self::abs_diff_eq(&self, rhs, max_abs_diff) }
+			fn abs(&self) -> Self { self.abs() }
+		}
+	};
+}
+
+impl_gmat!(Mat2, f32, Vec2, 2, mul_vec2, mul_mat2, add_mat2, sub_mat2);
+impl_gmat!(Mat3, f32, Vec3, 3, mul_vec3, mul_mat3, add_mat3, sub_mat3);
+impl_gmat!(Mat3A, f32, Vec3A, 3, mul_vec3a, mul_mat3, add_mat3, sub_mat3);
+impl_gmat!(Mat4, f32, Vec4, 4, mul_vec4, mul_mat4, add_mat4, sub_mat4);
+impl_gmat!(DMat2, f64, DVec2, 2, mul_vec2, mul_mat2, add_mat2, sub_mat2);
+impl_gmat!(DMat3, f64, DVec3, 3, mul_vec3, mul_mat3, add_mat3, sub_mat3);
+impl_gmat!(DMat4, f64, DVec4, 4, mul_vec4, mul_mat4, add_mat4, sub_mat4);
+
+/**
+Generic matrix of size 2x2.
+
+[`GMat2::Vec2`] is the column type, the same as [`GMat::Col`].
+*/
+pub trait GMat2
+where
+	Self:
+		GMat<
+			Col = <Self as GMat2>::Vec2,
+			Array = [<Self as GMat>::Scalar; 4],
+			Array2d = [[<Self as GMat>::Scalar; 2]; 2],
+		> +
+	,
+	Self::Vec2: FloatVec2<Scalar = Self::Scalar>,
+	Self::Mat3: GMat3<Vec2 = Self::Vec2, Mat2 = Self> + GMat<Scalar = <Self as GMat>::Scalar>,
+{
+	type Vec2;
+	type Mat3;
+	fn from_cols(x_axis: Self::Vec2, y_axis: Self::Vec2) -> Self;
+	fn from_scale_angle(scale: Self::Vec2, angle: Self::Scalar) -> Self;
+	fn from_angle(angle: Self::Scalar) -> Self;
+	fn from_mat3(m: Self::Mat3) -> Self;
+	fn from_mat3_minor(m: Self::Mat3, i: usize, j: usize) -> Self;
+}
+
+macro_rules! impl_gmat2 {
+	($type:ty, $vec2:ty, $mat3:ty) => {
+		impl GMat2 for $type {
+			type Vec2 = $vec2;
+			type Mat3 = $mat3;
+			fn from_cols(x_axis: Self::Vec2, y_axis: Self::Vec2) -> Self { Self::from_cols(x_axis, y_axis) }
+			fn from_scale_angle(scale: Self::Vec2, angle: Self::Scalar) -> Self { Self::from_scale_angle(scale, angle) }
+			fn from_angle(angle: Self::Scalar) -> Self { Self::from_angle(angle) }
+			fn from_mat3(m: Self::Mat3) -> Self { Self::from_mat3(m) }
+			fn from_mat3_minor(m: Self::Mat3, i: usize, j: usize) -> Self { Self::from_mat3_minor(m, i, j) }
+		}
+	};
+}
+
+impl_gmat2!(Mat2, Vec2, Mat3);
+impl_gmat2!(DMat2, DVec2, DMat3);
+
+/**
+Generic matrix of size 3x3.
+
+[`GMat3::Vec3`] is the column type, the same as [`GMat::Col`].
+*/
+pub trait GMat3
+where
+	Self:
+		GMat<
+			Col = <Self as GMat3>::Vec3,
+			Array = [<Self as GMat>::Scalar; 9],
+			Array2d = [[<Self as GMat>::Scalar; 3]; 3],
+		> +
+	,
+	Self::Vec2: FloatVec2<Scalar = Self::Scalar>,
+	Self::Vec3: FloatVec3<Scalar = Self::Scalar>,
+	Self::Mat2: GMat2<Vec2 = Self::Vec2> + GMat<Scalar = <Self as GMat>::Scalar>,
+	Self::Mat4: GMat4 + GMat<Scalar = <Self as GMat>::Scalar>,
+{
+	type Vec2;
+	type Vec3;
+	type Mat2;
+	type Mat4;
+	fn from_cols(x_axis: Self::Vec3, y_axis: Self::Vec3, z_axis: Self::Vec3) -> Self;
+	fn from_mat2(m: Self::Mat2) -> Self;
+	fn from_mat4(m: Self::Mat4) -> Self;
+	fn from_mat4_minor(m: Self::Mat4, i: usize, j: usize) -> Self;
+	fn from_quat(rotation: <Self::Vec3 as FloatVec3>::Quat) -> Self;
+	fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+	fn from_euler(order: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self;
+	fn to_euler(&self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar);
+	fn from_rotation_x(angle: Self::Scalar) -> Self;
+	fn from_rotation_y(angle: Self::Scalar) -> Self;
+	fn from_rotation_z(angle: Self::Scalar) -> Self;
+	fn from_translation(translation: Self::Vec2) -> Self;
+	fn from_angle(angle: Self::Scalar) -> Self;
+	fn from_scale_angle_translation(scale: Self::Vec2, angle: Self::Scalar, translation: Self::Vec2) -> Self;
+	fn from_scale(scale: Self::Vec2) -> Self;
+	fn transform_point2(&self, rhs: Self::Vec2) -> Self::Vec2;
+	fn transform_vector2(&self, rhs: Self::Vec2) -> Self::Vec2;
+	fn look_to_lh(dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_to_rh(dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+}
+
+macro_rules! impl_gmat3 {
+	($type:ty, $vec2:ty, $vec3:ty, $mat2:ty, $mat4:ty) => {
+		impl GMat3 for $type {
+			type Vec2 = $vec2;
+			type Vec3 = $vec3;
+			type Mat2 = $mat2;
+			type Mat4 = $mat4;
+			fn from_cols(x_axis: Self::Vec3, y_axis: Self::Vec3, z_axis: Self::Vec3) -> Self { Self::from_cols(x_axis, y_axis, z_axis) }
+			fn from_mat2(m: Self::Mat2) -> Self { Self::from_mat2(m) }
+			fn from_mat4(m: Self::Mat4) -> Self { Self::from_mat4(m) }
+			fn from_mat4_minor(m: Self::Mat4, i: usize, j: usize) -> Self { Self::from_mat4_minor(m, i, j) }
+			fn from_quat(rotation: <Self::Vec3 as FloatVec3>::Quat) -> Self { Self::from_quat(rotation) }
+			fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self { Self::from_axis_angle(axis.into(), angle) }
+			fn from_euler(order: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self { Self::from_euler(order, a, b, c) }
+			fn to_euler(&self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar) { self.to_euler(order) }
+			fn from_rotation_x(angle: Self::Scalar) -> Self { Self::from_rotation_x(angle) }
+			fn from_rotation_y(angle: Self::Scalar) -> Self { Self::from_rotation_y(angle) }
+			fn from_rotation_z(angle: Self::Scalar) -> Self { Self::from_rotation_z(angle) }
+			fn from_translation(translation: Self::Vec2) -> Self { Self::from_translation(translation) }
+			fn from_angle(angle: Self::Scalar) -> Self { Self::from_angle(angle) }
+			fn from_scale_angle_translation(scale: Self::Vec2, angle: Self::Scalar, translation: Self::Vec2) -> Self { Self::from_scale_angle_translation(scale, angle, translation) }
+			fn from_scale(scale: Self::Vec2) -> Self { Self::from_scale(scale) }
+			fn transform_point2(&self, rhs: Self::Vec2) -> Self::Vec2 { self.transform_point2(rhs) }
+			fn transform_vector2(&self, rhs: Self::Vec2) -> Self::Vec2 { self.transform_vector2(rhs) }
+			fn look_to_lh(dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_lh(dir.into(), up.into()) }
+			fn look_to_rh(dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_rh(dir.into(), up.into()) }
+			fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_lh(eye.into(), center.into(), up.into()) }
+			fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_rh(eye.into(), center.into(), up.into()) }
+		}
+	};
+}
+
+impl_gmat3!(Mat3, Vec2, Vec3, Mat2, Mat4);
+impl_gmat3!(Mat3A, Vec2, Vec3A, Mat2, Mat4);
+impl_gmat3!(DMat3, DVec2, DVec3, DMat2, DMat4);
+
+/**
+Generic matrix of size 4x4.
+
+[`GMat4::Vec4`] is the column type, the same as [`GMat::Col`].
+*/
+pub trait GMat4
+where
+	Self:
+		GMat<
+			Col = <Self as GMat4>::Vec4,
+			Array = [<Self as GMat>::Scalar; 16],
+			Array2d = [[<Self as GMat>::Scalar; 4]; 4],
+		> +
+	,
+	Self::Vec3: FloatVec3<Scalar = Self::Scalar>,
+	Self::Vec4: FloatVec4<Scalar = Self::Scalar>,
+	Self::Mat3: GMat3<Vec3 = Self::Vec3, Mat4 = Self> + GMat<Scalar = <Self as GMat>::Scalar>,
+{
+	type Vec3;
+	type Vec4;
+	type Mat3;
+	fn from_cols(x_axis: Self::Vec4, y_axis: Self::Vec4, z_axis: Self::Vec4, w_axis: Self::Vec4) -> Self;
+	fn from_mat3(m: Self::Mat3) -> Self;
+	fn from_mat3_translation(mat3: Self::Mat3, translation: Self::Vec3) -> Self;
+	fn from_quat(rotation: <Self::Vec3 as FloatVec3>::Quat) -> Self;
+	fn from_rotation_translation(rotation: <Self::Vec3 as FloatVec3>::Quat, translation: Self::Vec3) -> Self;
+	fn from_scale_rotation_translation(scale: Self::Vec3, rotation: <Self::Vec3 as FloatVec3>::Quat, translation: Self::Vec3) -> Self;
+	fn to_scale_rotation_translation(&self) -> (Self::Vec3, <Self::Vec3 as FloatVec3>::Quat, Self::Vec3);
+	fn from_translation(translation: Self::Vec3) -> Self;
+	fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+	fn from_euler(order: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self;
+	fn to_euler(&self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar);
+	fn from_rotation_x(angle: Self::Scalar) -> Self;
+	fn from_rotation_y(angle: Self::Scalar) -> Self;
+	fn from_rotation_z(angle: Self::Scalar) -> Self;
+	fn from_scale(scale: Self::Vec3) -> Self;
+	fn look_to_lh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_to_rh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn frustum_rh_gl(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn frustum_lh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn frustum_rh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn perspective_rh_gl(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn perspective_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn perspective_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self;
+	fn perspective_infinite_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self;
+	fn perspective_infinite_reverse_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self;
+	fn perspective_infinite_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self;
+	fn perspective_infinite_reverse_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self;
+	fn orthographic_rh_gl(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self;
+	fn orthographic_lh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self;
+	fn orthographic_rh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self;
+	fn project_point3(&self, rhs: Self::Vec3) -> Self::Vec3;
+	fn transform_point3(&self, rhs: Self::Vec3) -> Self::Vec3;
+	fn transform_vector3(&self, rhs: Self::Vec3) -> Self::Vec3;
+}
+
+macro_rules! impl_gmat4 {
+	($type:ty, $vec3:ty, $vec4:ty, $mat3:ty) => {
+		impl GMat4 for $type {
+			type Vec3 = $vec3;
+			type Vec4 = $vec4;
+			type Mat3 = $mat3;
+			fn from_cols(x_axis: Self::Vec4, y_axis: Self::Vec4, z_axis: Self::Vec4, w_axis: Self::Vec4) -> Self { Self::from_cols(x_axis, y_axis, z_axis, w_axis) }
+			fn from_mat3(m: Self::Mat3) -> Self { Self::from_mat3(m) }
+			fn from_mat3_translation(mat3: Self::Mat3, translation: Self::Vec3) -> Self { Self::from_mat3_translation(mat3, translation) }
+			fn from_quat(rotation: <Self::Vec3 as FloatVec3>::Quat) -> Self { Self::from_quat(rotation) }
+			fn from_rotation_translation(rotation: <Self::Vec3 as FloatVec3>::Quat, translation: Self::Vec3) -> Self { Self::from_rotation_translation(rotation, translation) }
+			fn from_scale_rotation_translation(scale: Self::Vec3, rotation: <Self::Vec3 as FloatVec3>::Quat, translation: Self::Vec3) -> Self { Self::from_scale_rotation_translation(scale, rotation, translation) }
+			fn to_scale_rotation_translation(&self) -> (Self::Vec3, <Self::Vec3 as FloatVec3>::Quat, Self::Vec3) { self.to_scale_rotation_translation() }
+			fn from_translation(translation: Self::Vec3) -> Self { Self::from_translation(translation) }
+			fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self { Self::from_axis_angle(axis, angle) }
+			fn from_euler(order: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self { Self::from_euler(order, a, b, c) }
+			fn to_euler(&self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar) { self.to_euler(order) }
+			fn from_rotation_x(angle: Self::Scalar) -> Self { Self::from_rotation_x(angle) }
+			fn from_rotation_y(angle: Self::Scalar) -> Self { Self::from_rotation_y(angle) }
+			fn from_rotation_z(angle: Self::Scalar) -> Self { Self::from_rotation_z(angle) }
+			fn from_scale(scale: Self::Vec3) -> Self { Self::from_scale(scale) }
+			fn look_to_lh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_lh(eye, dir, up) }
+			fn look_to_rh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_rh(eye, dir, up) }
+			fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_lh(eye, center, up) }
+			fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_rh(eye, center, up) }
+			fn frustum_rh_gl(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::frustum_rh_gl(left, right, bottom, top, z_near, z_far) }
+			fn frustum_lh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::frustum_lh(left, right, bottom, top, z_near, z_far) }
+			fn frustum_rh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::frustum_rh(left, right, bottom, top, z_near, z_far) }
+			fn perspective_rh_gl(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::perspective_rh_gl(fov_y_radians, aspect_ratio, z_near, z_far) }
+			fn perspective_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::perspective_lh(fov_y_radians, aspect_ratio, z_near, z_far) }
+			fn perspective_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar, z_far: Self::Scalar) -> Self { Self::perspective_rh(fov_y_radians, aspect_ratio, z_near, z_far) }
+			fn perspective_infinite_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self { Self::perspective_infinite_lh(fov_y_radians, aspect_ratio, z_near) }
+			fn perspective_infinite_reverse_lh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self { Self::perspective_infinite_reverse_lh(fov_y_radians, aspect_ratio, z_near) }
+			fn perspective_infinite_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self { Self::perspective_infinite_rh(fov_y_radians, aspect_ratio, z_near) }
+			fn perspective_infinite_reverse_rh(fov_y_radians: Self::Scalar, aspect_ratio: Self::Scalar, z_near: Self::Scalar) -> Self { Self::perspective_infinite_reverse_rh(fov_y_radians, aspect_ratio, z_near) }
+			fn orthographic_rh_gl(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self { Self::orthographic_rh_gl(left, right, bottom, top, near, far) }
+			fn orthographic_lh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self { Self::orthographic_lh(left, right, bottom, top, near, far) }
+			fn orthographic_rh(left: Self::Scalar, right: Self::Scalar, bottom: Self::Scalar, top: Self::Scalar, near: Self::Scalar, far: Self::Scalar) -> Self { Self::orthographic_rh(left, right, bottom, top, near, far) }
+			fn project_point3(&self, rhs: Self::Vec3) -> Self::Vec3 { self.project_point3(rhs) }
+			fn transform_point3(&self, rhs: Self::Vec3) -> Self::Vec3 { self.transform_point3(rhs) }
+			fn transform_vector3(&self, rhs: Self::Vec3) -> Self::Vec3 { self.transform_vector3(rhs) }
+		}
+	};
+}
+
+impl_gmat4!(Mat4, Vec3, Vec4, Mat3);
+impl_gmat4!(DMat4, DVec3, DVec4, DMat3);
+
+/**
+Generic quaternion.
+*/
+pub trait GQuat
+where
+	for <'a> Self:
+		Sealed +
+		Serde +
+		Approx<Self::Scalar> +
+		Clone +
+		Copy +
+		PartialEq +
+		Default +
+		Display +
+		Debug +
+		Add<Output = Self> +
+		Add<&'a Self, Output = Self> +
+		AddAssign +
+		AddAssign<&'a Self> +
+		Sub<Output = Self> +
+		Sub<&'a Self, Output = Self> +
+		SubAssign +
+		SubAssign<&'a Self> +
+		Mul<Output = Self> +
+		Mul<&'a Self, Output = Self> +
+		Mul<Self::Scalar, Output = Self> +
+		Mul<&'a Self::Scalar, Output = Self> +
+		Mul<Self::Vec3, Output = Self::Vec3> +
+		Mul<&'a Self::Vec3, Output = Self::Vec3> +
+		MulAssign +
+		MulAssign<&'a Self> +
+		MulAssign<Self::Scalar> +
+		MulAssign<&'a Self::Scalar> +
+		Div<Self::Scalar, Output = Self> +
+		Div<&'a Self::Scalar, Output = Self> +
+		DivAssign<Self::Scalar> +
+		DivAssign<&'a Self::Scalar> +
+		Neg<Output = Self> +
+		Sum +
+		Sum<&'a Self> +
+		Product +
+		Product<&'a Self> +
+		AsRef<[Self::Scalar; 4]> +
+		Into<[Self::Scalar; 4]> +
+		Into<Self::Vec4> +
+	,
+	Self::Scalar: FloatScalar,
+	Self::Vec2: FloatVec2<Scalar = Self::Scalar>,
+	Self::Vec3: FloatVec3<Scalar = Self::Scalar, Quat = Self>,
+	Self::Vec4: FloatVec4<Scalar = Self::Scalar>,
+	Self::Mat3: GMat3<Vec3 = Self::Vec3> + GMat<Scalar = Self::Scalar>,
+	Self::Mat4: GMat4<Vec3 = Self::Vec3> + GMat<Scalar = Self::Scalar>,
+{
+	type Scalar;
+	type Vec2;
+	type Vec3;
+	type Vec4;
+	type Mat3;
+	type Mat4;
+	const IDENTITY: Self;
+	const NAN: Self;
+	fn from_xyzw(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self;
+	fn from_array(a: [Self::Scalar; 4]) -> Self;
+	fn from_vec4(v: Self::Vec4) -> Self;
+	fn from_slice(slice: &[Self::Scalar]) -> Self;
+	fn write_to_slice(self, slice: &mut [Self::Scalar]);
+	fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+	fn from_scaled_axis(v: Self::Vec3) -> Self;
+	fn from_rotation_x(angle: Self::Scalar) -> Self;
+	fn from_rotation_y(angle: Self::Scalar) -> Self;
+	fn from_rotation_z(angle: Self::Scalar) -> Self;
+	fn from_euler(euler: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self;
+	fn from_mat3(mat: &Self::Mat3) -> Self;
+	fn from_mat4(mat: &Self::Mat4) -> Self;
+	fn from_rotation_arc(from: Self::Vec3, to: Self::Vec3) -> Self;
+	fn from_rotation_arc_colinear(from: Self::Vec3, to: Self::Vec3) -> Self;
+	fn from_rotation_arc_2d(from: Self::Vec2, to: Self::Vec2) -> Self;
+	fn look_to_lh(dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_to_rh(dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn to_axis_angle(self) -> (Self::Vec3, Self::Scalar);
+	fn to_scaled_axis(self) -> Self::Vec3;
+	fn to_euler(self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar);
+	fn to_array(&self) -> [Self::Scalar; 4];
+	fn xyz(self) -> Self::Vec3;
+	fn conjugate(self) -> Self;
+	fn inverse(self) -> Self;
+	fn dot(self, rhs: Self) -> Self::Scalar;
+	fn length(self) -> Self::Scalar;
+	fn length_squared(self) -> Self::Scalar;
+	fn length_recip(self) -> Self::Scalar;
+	fn normalize(self) -> Self;
+	fn is_finite(self) -> bool;
+	fn is_nan(self) -> bool;
+	fn is_normalized(self) -> bool;
+	fn is_near_identity(self) -> bool;
+	fn angle_between(self, rhs: Self) -> Self::Scalar;
+	fn rotate_towards(&self, rhs: Self, max_angle: Self::Scalar) -> Self;
+	fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool;
+	fn lerp(self, end: Self, s: Self::Scalar) -> Self;
+	fn slerp(self, end: Self, s: Self::Scalar) -> Self;
+	fn mul_vec3(self, rhs: Self::Vec3) -> Self::Vec3;
+	fn mul_quat(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_gquat {
+	($type:ty, $scalar:ty, $vec2:ty, $vec3:ty, $vec4:ty, $mat3:ty, $mat4:ty) => {
+		impl Sealed for $type {}
+		impl GQuat for $type {
+			type Scalar = $scalar;
+			type Vec2 = $vec2;
+			type Vec3 = $vec3;
+			type Vec4 = $vec4;
+			type Mat3 = $mat3;
+			type Mat4 = $mat4;
+			const IDENTITY: Self = Self::IDENTITY;
+			const NAN: Self = Self::NAN;
+			fn from_xyzw(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self { Self::from_xyzw(x, y, z, w) }
+			fn from_array(a: [Self::Scalar; 4]) -> Self { Self::from_array(a) }
+			fn from_vec4(v: Self::Vec4) -> Self { Self::from_vec4(v) }
+			fn from_slice(slice: &[Self::Scalar]) -> Self { Self::from_slice(slice) }
+			fn write_to_slice(self, slice: &mut [Self::Scalar]) { self.write_to_slice(slice) }
+			fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self { Self::from_axis_angle(axis, angle) }
+			fn from_scaled_axis(v: Self::Vec3) -> Self { Self::from_scaled_axis(v) }
+			fn from_rotation_x(angle: Self::Scalar) -> Self { Self::from_rotation_x(angle) }
+			fn from_rotation_y(angle: Self::Scalar) -> Self { Self::from_rotation_y(angle) }
+			fn from_rotation_z(angle: Self::Scalar) -> Self { Self::from_rotation_z(angle) }
+			fn from_euler(euler: EulerRot, a: Self::Scalar, b: Self::Scalar, c: Self::Scalar) -> Self { Self::from_euler(euler, a, b, c) }
+			fn from_mat3(mat: &Self::Mat3) -> Self { Self::from_mat3(mat) }
+			fn from_mat4(mat: &Self::Mat4) -> Self { Self::from_mat4(mat) }
+			fn from_rotation_arc(from: Self::Vec3, to: Self::Vec3) -> Self { Self::from_rotation_arc(from, to) }
+			fn from_rotation_arc_colinear(from: Self::Vec3, to: Self::Vec3) -> Self { Self::from_rotation_arc_colinear(from, to) }
+			fn from_rotation_arc_2d(from: Self::Vec2, to: Self::Vec2) -> Self { Self::from_rotation_arc_2d(from, to) }
+			fn look_to_lh(dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_lh(dir, up) }
+			fn look_to_rh(dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_rh(dir, up) }
+			fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_lh(eye, center, up) }
+			fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_rh(eye, center, up) }
+			fn to_axis_angle(self) -> (Self::Vec3, Self::Scalar) { self.to_axis_angle() }
+			fn to_scaled_axis(self) -> Self::Vec3 { self.to_scaled_axis() }
+			fn to_euler(self, order: EulerRot) -> (Self::Scalar, Self::Scalar, Self::Scalar) { self.to_euler(order) }
+			fn to_array(&self) -> [Self::Scalar; 4] { self.to_array() }
+			fn xyz(self) -> Self::Vec3 { self.xyz() }
+			fn conjugate(self) -> Self { self.conjugate() }
+			fn inverse(self) -> Self { self.inverse() }
+			fn dot(self, rhs: Self) -> Self::Scalar { self.dot(rhs) }
+			fn length(self) -> Self::Scalar { self.length() }
+			fn length_squared(self) -> Self::Scalar { self.length_squared() }
+			fn length_recip(self) -> Self::Scalar { self.length_recip() }
+			fn normalize(self) -> Self { self.normalize() }
+			fn is_finite(self) -> bool { self.is_finite() }
+			fn is_nan(self) -> bool { self.is_nan() }
+			fn is_normalized(self) -> bool { self.is_normalized() }
+			fn is_near_identity(self) -> bool { self.is_near_identity() }
+			fn angle_between(self, rhs: Self) -> Self::Scalar { self.angle_between(rhs) }
+			fn rotate_towards(&self, rhs: Self, max_angle: Self::Scalar) -> Self { self.rotate_towards(rhs, max_angle) }
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool { self.abs_diff_eq(rhs, max_abs_diff) }
+			fn lerp(self, end: Self, s: Self::Scalar) -> Self { self.lerp(end, s) }
+			fn slerp(self, end: Self, s: Self::Scalar) -> Self { self.slerp(end, s) }
+			fn mul_vec3(self, rhs: Self::Vec3) -> Self::Vec3 { self.mul_vec3(rhs) }
+			fn mul_quat(self, rhs: Self) -> Self { self.mul_quat(rhs) }
+		}
+	};
+}
+
+impl_gquat!(Quat, f32, Vec2, Vec3, Vec4, Mat3, Mat4);
+impl_gquat!(DQuat, f64, DVec2, DVec3, DVec4, DMat3, DMat4);
+
+/**
+Generic affine transform of any dimension.
+*/
+pub trait GAffine
+where
+	for <'a> Self:
+		Sealed +
+		Serde +
+		Approx<Self::Scalar> +
+		Clone +
+		Copy +
+		PartialEq +
+		Default +
+		Display +
+		Debug +
+		Mul<Output = Self> +
+		Mul<&'a Self, Output = Self> +
+		MulAssign +
+		MulAssign<&'a Self> +
+		Product<&'a Self> +
+	,
+	Self::Scalar: FloatScalar,
+	Self::Col: FloatVec<Scalar = Self::Scalar>,
+	Self::Array: Index<usize, Output = Self::Scalar>,
+	Self::Array2d: Index<usize, Output = <Self::Col as GVec>::Array>,
+{
+	type Scalar;
+	type Col;
+	type Array;
+	type Array2d;
+	const ZERO: Self;
+	const IDENTITY: Self;
+	const NAN: Self;
+	fn from_cols_array(m: &Self::Array) -> Self;
+	fn to_cols_array(&self) -> Self::Array;
+	fn from_cols_array_2d(m: &Self::Array2d) -> Self;
+	fn to_cols_array_2d(&self) -> Self::Array2d;
+	fn from_cols_slice(slice: &[Self::Scalar]) -> Self;
+	fn write_cols_to_slice(self, slice: &mut [Self::Scalar]);
+	fn translation(&self) -> Self::Col;
+	fn is_finite(&self) -> bool;
+	fn is_nan(&self) -> bool;
+	fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool;
+	fn inverse(&self) -> Self;
+}
+
+macro_rules! impl_gaffine {
+	($type:ty, $scalar:ty, $col:ty, $dim:literal) => {
+		impl Sealed for $type {}
+		impl GAffine for $type {
+			type Scalar = $scalar;
+			type Col = $col;
+			type Array = [$scalar; $dim * ($dim + 1)];
+			type Array2d = [[$scalar; $dim]; $dim + 1];
+			const ZERO: Self = Self::ZERO;
+			const IDENTITY: Self = Self::IDENTITY;
+			const NAN: Self = Self::NAN;
+			fn from_cols_array(m: &Self::Array) -> Self { Self::from_cols_array(m) }
+			fn to_cols_array(&self) -> Self::Array { self.to_cols_array() }
+			fn from_cols_array_2d(m: &Self::Array2d) -> Self { Self::from_cols_array_2d(m) }
+			fn to_cols_array_2d(&self) -> Self::Array2d { self.to_cols_array_2d() }
+			fn from_cols_slice(slice: &[Self::Scalar]) -> Self { Self::from_cols_slice(slice) }
+			fn write_cols_to_slice(self, slice: &mut [Self::Scalar]) { self.write_cols_to_slice(slice) }
+			fn translation(&self) -> Self::Col { self.translation }
+			fn is_finite(&self) -> bool { self.is_finite() }
+			fn is_nan(&self) -> bool { self.is_nan() }
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool { Self::abs_diff_eq(&self, rhs, max_abs_diff) }
+			fn inverse(&self) -> Self { self.inverse() }
+		}
+	};
+}
+
+impl_gaffine!(Affine2, f32, Vec2, 2);
+impl_gaffine!(Affine3A, f32, Vec3A, 3);
+impl_gaffine!(DAffine2, f64, DVec2, 2);
+impl_gaffine!(DAffine3, f64, DVec3, 3);
+
+/**
+Generic 2D affine transform.
+
+[`GAffine2::Vec2`] is the column type, the same as [`GAffine::Col`].
+*/
+pub trait GAffine2
+where
+	Self:
+		GAffine<
+			Col = <Self as GAffine2>::Vec2,
+			Array = [<Self as GAffine>::Scalar; 6],
+			Array2d = [[<Self as GAffine>::Scalar; 2]; 3],
+		> +
+		Mul<Self::Mat3, Output = Self::Mat3> +
+		Into<Self::Mat3> +
+	,
+	Self::Vec2: FloatVec2<Scalar = <Self as GAffine>::Scalar>,
+	Self::Mat2: GMat2<Vec2 = Self::Vec2> + GMat<Scalar = <Self as GAffine>::Scalar>,
+	Self::Mat3: GMat3<Vec2 = Self::Vec2> + GMat<Scalar = <Self as GAffine>::Scalar>,
+{
+	type Vec2;
+	type Mat2;
+	type Mat3;
+	fn from_cols(x_axis: Self::Vec2, y_axis: Self::Vec2, z_axis: Self::Vec2) -> Self;
+	fn from_scale(scale: Self::Vec2) -> Self;
+	fn from_angle(angle: Self::Scalar) -> Self;
+	fn from_translation(translation: Self::Vec2) -> Self;
+	fn from_mat2(matrix2: Self::Mat2) -> Self;
+	fn from_mat2_translation(matrix2: Self::Mat2, translation: Self::Vec2) -> Self;
+	fn from_scale_angle_translation(scale: Self::Vec2, angle: Self::Scalar, translation: Self::Vec2) -> Self;
+	fn from_angle_translation(angle: Self::Scalar, translation: Self::Vec2) -> Self;
+	fn from_mat3(m: Self::Mat3) -> Self;
+	fn to_scale_angle_translation(self) -> (Self::Vec2, Self::Scalar, Self::Vec2);
+	fn matrix2(&self) -> Self::Mat2;
+	fn transform_point2(&self, rhs: Self::Vec2) -> Self::Vec2;
+	fn transform_vector2(&self, rhs: Self::Vec2) -> Self::Vec2;
+}
+
+macro_rules! impl_gaffine2 {
+	($type:ty, $vec2:ty, $mat2:ty, $mat3:ty) => {
+		impl GAffine2 for $type {
+			type Vec2 = $vec2;
+			type Mat2 = $mat2;
+			type Mat3 = $mat3;
+			fn from_cols(x_axis: Self::Vec2, y_axis: Self::Vec2, z_axis: Self::Vec2) -> Self { Self::from_cols(x_axis, y_axis, z_axis) }
+			fn from_scale(scale: Self::Vec2) -> Self { Self::from_scale(scale) }
+			fn from_angle(angle: Self::Scalar) -> Self { Self::from_angle(angle) }
+			fn from_translation(translation: Self::Vec2) -> Self { Self::from_translation(translation) }
+			fn from_mat2(matrix2: Self::Mat2) -> Self { Self::from_mat2(matrix2) }
+			fn from_mat2_translation(matrix2: Self::Mat2, translation: Self::Vec2) -> Self { Self::from_mat2_translation(matrix2, translation) }
+			fn from_scale_angle_translation(scale: Self::Vec2, angle: Self::Scalar, translation: Self::Vec2) -> Self { Self::from_scale_angle_translation(scale, angle, translation) }
+			fn from_angle_translation(angle: Self::Scalar, translation: Self::Vec2) -> Self { Self::from_angle_translation(angle, translation) }
+			fn from_mat3(m: Self::Mat3) -> Self { Self::from_mat3(m) }
+			fn to_scale_angle_translation(self) -> (Self::Vec2, Self::Scalar, Self::Vec2) { self.to_scale_angle_translation() }
+			fn matrix2(&self) -> Self::Mat2 { self.matrix2 }
+			fn transform_point2(&self, rhs: Self::Vec2) -> Self::Vec2 { self.transform_point2(rhs) }
+			fn transform_vector2(&self, rhs: Self::Vec2) -> Self::Vec2 { self.transform_vector2(rhs) }
+		}
+	};
+}
+
+impl_gaffine2!(Affine2, Vec2, Mat2, Mat3);
+impl_gaffine2!(DAffine2, DVec2, DMat2, DMat3);
+
+/**
+Generic 3D affine transform.
+
+[`GAffine3::Vec3`] is the column type, the same as [`GAffine::Col`].
+*/
+pub trait GAffine3
+where
+	Self:
+		GAffine<
+			Col = <Self as GAffine3>::Vec3,
+			Array = [<Self as GAffine>::Scalar; 12],
+			Array2d = [[<Self as GAffine>::Scalar; 3]; 4],
+		> +
+		Mul<Self::Mat4, Output = Self::Mat4> +
+		Into<Self::Mat4> +
+	,
+	Self::Vec3: FloatVec3<Scalar = <Self as GAffine>::Scalar, Quat = Self::Quat>,
+	Self::Quat: GQuat<Scalar = <Self as GAffine>::Scalar>,
+	Self::Mat3: GMat3<Vec3 = Self::Vec3> + GMat<Scalar = <Self as GAffine>::Scalar>,
+	Self::Mat4: GMat4 + GMat<Scalar = <Self as GAffine>::Scalar>,
+{
+	type Vec3;
+	type Quat;
+	type Mat3;
+	type Mat4;
+	fn from_cols(x_axis: Self::Vec3, y_axis: Self::Vec3, z_axis: Self::Vec3, w_axis: Self::Vec3) -> Self;
+	fn from_scale(scale: Self::Vec3) -> Self;
+	fn from_quat(rotation: Self::Quat) -> Self;
+	fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+	fn from_rotation_x(angle: Self::Scalar) -> Self;
+	fn from_rotation_y(angle: Self::Scalar) -> Self;
+	fn from_rotation_z(angle: Self::Scalar) -> Self;
+	fn from_translation(translation: Self::Vec3) -> Self;
+	fn from_mat3(mat3: Self::Mat3) -> Self;
+	fn from_mat3_translation(mat3: Self::Mat3, translation: Self::Vec3) -> Self;
+	fn from_scale_rotation_translation(scale: Self::Vec3, rotation: Self::Quat, translation: Self::Vec3) -> Self;
+	fn from_rotation_translation(rotation: Self::Quat, translation: Self::Vec3) -> Self;
+	fn from_mat4(m: Self::Mat4) -> Self;
+	fn to_scale_rotation_translation(&self) -> (Self::Vec3, Self::Quat, Self::Vec3);
+	fn matrix3(&self) -> Self::Mat3;
+	fn look_to_lh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_to_rh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+	fn transform_point3(&self, rhs: Self::Vec3) -> Self::Vec3;
+	fn transform_vector3(&self, rhs: Self::Vec3) -> Self::Vec3;
+}
+
+macro_rules! impl_gaffine3 {
+	($type:ty, $vec3:ty, $quat:ty, $mat3:ty, $mat4:ty, $transform_point3:ident, $transform_vector3:ident) => {
+		impl GAffine3 for $type {
+			type Vec3 = $vec3;
+			type Quat = $quat;
+			type Mat3 = $mat3;
+			type Mat4 = $mat4;
+			fn from_cols(x_axis: Self::Vec3, y_axis: Self::Vec3, z_axis: Self::Vec3, w_axis: Self::Vec3) -> Self { Self::from_cols(x_axis, y_axis, z_axis, w_axis) }
+			fn from_scale(scale: Self::Vec3) -> Self { Self::from_scale(scale.into()) }
+			fn from_quat(rotation: Self::Quat) -> Self { Self::from_quat(rotation) }
+			fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self { Self::from_axis_angle(axis.into(), angle) }
+			fn from_rotation_x(angle: Self::Scalar) -> Self { Self::from_rotation_x(angle) }
+			fn from_rotation_y(angle: Self::Scalar) -> Self { Self::from_rotation_y(angle) }
+			fn from_rotation_z(angle: Self::Scalar) -> Self { Self::from_rotation_z(angle) }
+			fn from_translation(translation: Self::Vec3) -> Self { Self::from_translation(translation.into()) }
+			fn from_mat3(mat3: Self::Mat3) -> Self { Self::from_mat3(mat3.into()) }
+			fn from_mat3_translation(mat3: Self::Mat3, translation: Self::Vec3) -> Self { Self::from_mat3_translation(mat3.into(), translation.into()) }
+			fn from_scale_rotation_translation(scale: Self::Vec3, rotation: Self::Quat, translation: Self::Vec3) -> Self { Self::from_scale_rotation_translation(scale.into(), rotation, translation.into()) }
+			fn from_rotation_translation(rotation: Self::Quat, translation: Self::Vec3) -> Self { Self::from_rotation_translation(rotation, translation.into()) }
+			fn from_mat4(m: Self::Mat4) -> Self { Self::from_mat4(m) }
+			fn to_scale_rotation_translation(&self) -> (Self::Vec3, Self::Quat, Self::Vec3) {
+				let (scale, rotation, translation) = self.to_scale_rotation_translation();
+				(scale.into(), rotation, translation.into())
+			}
+			fn matrix3(&self) -> Self::Mat3 { self.matrix3 }
+			fn look_to_lh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_lh(eye.into(), dir.into(), up.into()) }
+			fn look_to_rh(eye: Self::Vec3, dir: Self::Vec3, up: Self::Vec3) -> Self { Self::look_to_rh(eye.into(), dir.into(), up.into()) }
+			fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_lh(eye.into(), center.into(), up.into()) }
+			fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self { Self::look_at_rh(eye.into(), center.into(), up.into()) }
+			fn transform_point3(&self, rhs: Self::Vec3) -> Self::Vec3 { self.$transform_point3(rhs) }
+			fn transform_vector3(&self, rhs: Self::Vec3) -> Self::Vec3 { self.$transform_vector3(rhs) }
+		}
+	};
+}
+
+impl_gaffine3!(Affine3A, Vec3A, Quat, Mat3A, Mat4, transform_point3a, transform_vector3a);
+impl_gaffine3!(DAffine3, DVec3, DQuat, DMat3, DMat4, transform_point3, transform_vector3);
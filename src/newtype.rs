@@ -0,0 +1,1177 @@
+/*!
+Forwarding impls behind the derive macros of `glam-traits-derive`.
+*/
+
+pub use glam::{Vec3, Vec3A, Quat, DQuat, IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2Swizzles, Vec3Swizzles, Vec4Swizzles};
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
+
+#[cfg(feature = "approx")]
+pub use approx;
+
+#[cfg(feature = "approx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_approx {
+	($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "approx"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_approx {
+	($($item:item)*) => {};
+}
+
+#[cfg(feature = "num-traits")]
+pub use num_traits;
+
+#[cfg(feature = "num-traits")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_num {
+	($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "num-traits"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_num {
+	($($item:item)*) => {};
+}
+
+#[cfg(feature = "mint")]
+pub use mint;
+
+#[cfg(feature = "mint")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_mint {
+	($($item:item)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "mint"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_mint {
+	($($item:item)*) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype {
+	(@ops $t:ident, $s:ty, $f:tt, $op:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+		impl ::core::ops::$op for $t {
+			type Output = Self;
+			fn $method(self, rhs: Self) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, rhs.$f) } }
+		}
+		impl ::core::ops::$op<&$t> for $t {
+			type Output = Self;
+			fn $method(self, rhs: &Self) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, rhs.$f) } }
+		}
+		impl ::core::ops::$op<$s> for $t {
+			type Output = Self;
+			fn $method(self, rhs: $s) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, rhs) } }
+		}
+		impl ::core::ops::$op<&$s> for $t {
+			type Output = Self;
+			fn $method(self, rhs: &$s) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, *rhs) } }
+		}
+		impl ::core::ops::$op<$t> for $s {
+			type Output = $t;
+			fn $method(self, rhs: $t) -> $t { $t { $f: ::core::ops::$op::$method(self, rhs.$f) } }
+		}
+		impl ::core::ops::$op<&$t> for $s {
+			type Output = $t;
+			fn $method(self, rhs: &$t) -> $t { $t { $f: ::core::ops::$op::$method(self, rhs.$f) } }
+		}
+		impl ::core::ops::$assign for $t {
+			fn $assign_method(&mut self, rhs: Self) { ::core::ops::$assign::$assign_method(&mut self.$f, rhs.$f) }
+		}
+		impl ::core::ops::$assign<&$t> for $t {
+			fn $assign_method(&mut self, rhs: &Self) { ::core::ops::$assign::$assign_method(&mut self.$f, rhs.$f) }
+		}
+		impl ::core::ops::$assign<$s> for $t {
+			fn $assign_method(&mut self, rhs: $s) { ::core::ops::$assign::$assign_method(&mut self.$f, rhs) }
+		}
+		impl ::core::ops::$assign<&$s> for $t {
+			fn $assign_method(&mut self, rhs: &$s) { ::core::ops::$assign::$assign_method(&mut self.$f, *rhs) }
+		}
+	};
+	(@bitops $t:ident, $s:ty, $f:tt, $($op:ident, $method:ident),*) => {
+		$(
+			impl ::core::ops::$op for $t {
+				type Output = Self;
+				fn $method(self, rhs: Self) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, rhs.$f) } }
+			}
+			impl ::core::ops::$op<$s> for $t {
+				type Output = Self;
+				fn $method(self, rhs: $s) -> Self { Self { $f: ::core::ops::$op::$method(self.$f, rhs) } }
+			}
+		)*
+	};
+	(@shifts $t:ident, $f:tt, $($rhs:ty),*) => {
+		$(
+			impl ::core::ops::Shl<$rhs> for $t {
+				type Output = Self;
+				fn shl(self, rhs: $rhs) -> Self { Self { $f: ::core::ops::Shl::shl(self.$f, rhs) } }
+			}
+			impl ::core::ops::Shr<$rhs> for $t {
+				type Output = Self;
+				fn shr(self, rhs: $rhs) -> Self { Self { $f: ::core::ops::Shr::shr(self.$f, rhs) } }
+			}
+		)*
+	};
+	(@with $t:ident, $i:ty, $($scalar:ty),*) => {
+		$(
+			impl $crate::WithScalar<$scalar> for $t {
+				type Output = <$i as $crate::WithScalar<$scalar>>::Output;
+			}
+		)*
+	};
+	(@tuple $t:ident, $f:tt, $tuple:ty) => {
+		impl ::core::convert::From<$t> for $tuple {
+			fn from(v: $t) -> Self { ::core::convert::From::from(v.$f) }
+		}
+	};
+	(GVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::Sealed for $t {}
+		$crate::__newtype_num! {
+			impl $crate::newtype::num_traits::Zero for $t {
+				fn zero() -> Self { Self { $f: <$i as $crate::GVec>::ZERO } }
+				fn is_zero(&self) -> bool { self.$f == <$i as $crate::GVec>::ZERO }
+			}
+			impl $crate::newtype::num_traits::One for $t {
+				fn one() -> Self { Self { $f: <$i as $crate::GVec>::ONE } }
+			}
+			impl $crate::newtype::num_traits::Bounded for $t {
+				fn min_value() -> Self { Self { $f: <$i as $crate::GVec>::MIN } }
+				fn max_value() -> Self { Self { $f: <$i as $crate::GVec>::MAX } }
+			}
+		}
+		impl<X> ::core::convert::From<X> for $t where $i: ::core::convert::From<X> {
+			fn from(x: X) -> Self { Self { $f: ::core::convert::From::from(x) } }
+		}
+		#[allow(clippy::from_over_into)]
+		impl ::core::convert::Into<$i> for $t {
+			fn into(self) -> $i { self.$f }
+		}
+		impl ::core::convert::From<$t> for [$s; <$i as $crate::GVec>::DIM] {
+			fn from(v: $t) -> Self { ::core::convert::From::from(v.$f) }
+		}
+		impl ::core::convert::AsRef<<$i as $crate::GVec>::Array> for $t {
+			fn as_ref(&self) -> &<$i as $crate::GVec>::Array { ::core::convert::AsRef::as_ref(&self.$f) }
+		}
+		impl ::core::convert::AsMut<<$i as $crate::GVec>::Array> for $t {
+			fn as_mut(&mut self) -> &mut <$i as $crate::GVec>::Array { ::core::convert::AsMut::as_mut(&mut self.$f) }
+		}
+		impl ::core::fmt::Display for $t {
+			fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { ::core::fmt::Display::fmt(&self.$f, f) }
+		}
+		impl ::core::ops::Index<usize> for $t {
+			type Output = $s;
+			fn index(&self, index: usize) -> &Self::Output { &self.$f[index] }
+		}
+		impl ::core::ops::IndexMut<usize> for $t {
+			fn index_mut(&mut self, index: usize) -> &mut Self::Output { &mut self.$f[index] }
+		}
+		impl ::core::iter::Sum for $t {
+			fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self { Self { $f: iter.map(|v| v.$f).sum() } }
+		}
+		impl<'a> ::core::iter::Sum<&'a $t> for $t {
+			fn sum<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self { Self { $f: iter.map(|v| v.$f).sum() } }
+		}
+		impl ::core::iter::Product for $t {
+			fn product<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self { Self { $f: iter.map(|v| v.$f).product() } }
+		}
+		impl<'a> ::core::iter::Product<&'a $t> for $t {
+			fn product<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self { Self { $f: iter.map(|v| v.$f).product() } }
+		}
+		$crate::__newtype!(@ops $t, $s, $f, Add, add, AddAssign, add_assign);
+		$crate::__newtype!(@ops $t, $s, $f, Sub, sub, SubAssign, sub_assign);
+		$crate::__newtype!(@ops $t, $s, $f, Mul, mul, MulAssign, mul_assign);
+		$crate::__newtype!(@ops $t, $s, $f, Div, div, DivAssign, div_assign);
+		$crate::__newtype!(@ops $t, $s, $f, Rem, rem, RemAssign, rem_assign);
+		$crate::__newtype!(@with $t, $i, i8, u8, i16, u16, i32, u32, i64, u64, usize, f32, f64);
+		impl $crate::GVec for $t {
+			type Scalar = $s;
+			type BVec = <$i as $crate::GVec>::BVec;
+			type Axes = [Self; <$i as $crate::GVec>::DIM];
+			type Array = <$i as $crate::GVec>::Array;
+			type I8Vec = <$i as $crate::GVec>::I8Vec;
+			type U8Vec = <$i as $crate::GVec>::U8Vec;
+			type I16Vec = <$i as $crate::GVec>::I16Vec;
+			type U16Vec = <$i as $crate::GVec>::U16Vec;
+			type I32Vec = <$i as $crate::GVec>::I32Vec;
+			type U32Vec = <$i as $crate::GVec>::U32Vec;
+			type I64Vec = <$i as $crate::GVec>::I64Vec;
+			type U64Vec = <$i as $crate::GVec>::U64Vec;
+			type USizeVec = <$i as $crate::GVec>::USizeVec;
+			type F32Vec = <$i as $crate::GVec>::F32Vec;
+			type F64Vec = <$i as $crate::GVec>::F64Vec;
+			const ZERO: Self = Self { $f: <$i as $crate::GVec>::ZERO };
+			const ONE: Self = Self { $f: <$i as $crate::GVec>::ONE };
+			const MIN: Self = Self { $f: <$i as $crate::GVec>::MIN };
+			const MAX: Self = Self { $f: <$i as $crate::GVec>::MAX };
+			const AXES: Self::Axes = {
+				let mut axes = [Self::ZERO; <$i as $crate::GVec>::DIM];
+				let mut i = 0;
+				while i < <$i as $crate::GVec>::DIM {
+					axes[i] = Self { $f: <$i as $crate::GVec>::AXES[i] };
+					i += 1;
+				}
+				axes
+			};
+			const DIM: usize = <$i as $crate::GVec>::DIM;
+			fn splat(v: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec>::splat(v) } }
+			fn map<F: Fn(Self::Scalar) -> Self::Scalar>(self, f: F) -> Self { Self { $f: <$i as $crate::GVec>::map(self.$f, f) } }
+			fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self { Self { $f: <$i as $crate::GVec>::from_fn(f) } }
+			fn iter(&self) -> ::core::slice::Iter<'_, Self::Scalar> { <$i as $crate::GVec>::iter(&self.$f) }
+			fn into_iter(self) -> <Self::Array as ::core::iter::IntoIterator>::IntoIter { <$i as $crate::GVec>::into_iter(self.$f) }
+			fn zip_map<F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar>(self, rhs: Self, f: F) -> Self { Self { $f: <$i as $crate::GVec>::zip_map(self.$f, rhs.$f, f) } }
+			fn map_to<W: $crate::GScalar, F: FnMut(Self::Scalar) -> W>(self, mut f: F) -> <Self as $crate::WithScalar<W>>::Output where Self: $crate::WithScalar<W> {
+				let a = <$i as $crate::GVec>::to_array(&self.$f);
+				<<Self as $crate::WithScalar<W>>::Output as $crate::GVec>::from_fn(|i| f(a[i]))
+			}
+			fn fold<B, F: FnMut(B, Self::Scalar) -> B>(self, init: B, f: F) -> B { <$i as $crate::GVec>::fold(self.$f, init, f) }
+			fn from_array(a: Self::Array) -> Self { Self { $f: <$i as $crate::GVec>::from_array(a) } }
+			fn to_array(&self) -> Self::Array { <$i as $crate::GVec>::to_array(&self.$f) }
+			fn from_slice(slice: &[Self::Scalar]) -> Self { Self { $f: <$i as $crate::GVec>::from_slice(slice) } }
+			fn write_to_slice(self, slice: &mut [Self::Scalar]) { <$i as $crate::GVec>::write_to_slice(self.$f, slice) }
+			fn try_from_slice(slice: &[Self::Scalar]) -> ::core::result::Result<Self, $crate::SliceLenError> { <$i as $crate::GVec>::try_from_slice(slice).map(|v| Self { $f: v }) }
+			fn try_write_to_slice(self, slice: &mut [Self::Scalar]) -> ::core::result::Result<(), $crate::SliceLenError> { <$i as $crate::GVec>::try_write_to_slice(self.$f, slice) }
+			fn write_chunks_to_slice(vecs: &[Self], slice: &mut [Self::Scalar]) -> ::core::result::Result<(), $crate::SliceLenError> {
+				let expected = vecs.len() * <$i as $crate::GVec>::DIM;
+				if slice.len() < expected {
+					return Err($crate::SliceLenError { expected, actual: slice.len() });
+				}
+				for (v, chunk) in vecs.iter().zip(slice.chunks_exact_mut(<$i as $crate::GVec>::DIM)) {
+					<$i as $crate::GVec>::write_to_slice(v.$f, chunk);
+				}
+				Ok(())
+			}
+			fn dot(self, rhs: Self) -> Self::Scalar { <$i as $crate::GVec>::dot(self.$f, rhs.$f) }
+			fn dot_into_vec(self, rhs: Self) -> Self { Self { $f: <$i as $crate::GVec>::dot_into_vec(self.$f, rhs.$f) } }
+			fn min(self, rhs: Self) -> Self { Self { $f: <$i as $crate::GVec>::min(self.$f, rhs.$f) } }
+			fn max(self, rhs: Self) -> Self { Self { $f: <$i as $crate::GVec>::max(self.$f, rhs.$f) } }
+			fn clamp(self, min: Self, max: Self) -> Self { Self { $f: <$i as $crate::GVec>::clamp(self.$f, min.$f, max.$f) } }
+			fn min_element(self) -> Self::Scalar { <$i as $crate::GVec>::min_element(self.$f) }
+			fn max_element(self) -> Self::Scalar { <$i as $crate::GVec>::max_element(self.$f) }
+			fn min_position(self) -> usize { <$i as $crate::GVec>::min_position(self.$f) }
+			fn max_position(self) -> usize { <$i as $crate::GVec>::max_position(self.$f) }
+			fn element_sum(self) -> Self::Scalar { <$i as $crate::GVec>::element_sum(self.$f) }
+			fn element_product(self) -> Self::Scalar { <$i as $crate::GVec>::element_product(self.$f) }
+			fn select(mask: Self::BVec, if_true: Self, if_false: Self) -> Self { Self { $f: <$i as $crate::GVec>::select(mask, if_true.$f, if_false.$f) } }
+			fn cmpeq(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmpeq(self.$f, rhs.$f) }
+			fn cmpne(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmpne(self.$f, rhs.$f) }
+			fn cmpge(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmpge(self.$f, rhs.$f) }
+			fn cmpgt(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmpgt(self.$f, rhs.$f) }
+			fn cmple(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmple(self.$f, rhs.$f) }
+			fn cmplt(self, rhs: Self) -> Self::BVec { <$i as $crate::GVec>::cmplt(self.$f, rhs.$f) }
+			fn length_squared(self) -> Self::Scalar { <$i as $crate::GVec>::length_squared(self.$f) }
+			fn as_i8vec(&self) -> Self::I8Vec { <$i as $crate::GVec>::as_i8vec(&self.$f) }
+			fn as_u8vec(&self) -> Self::U8Vec { <$i as $crate::GVec>::as_u8vec(&self.$f) }
+			fn as_i16vec(&self) -> Self::I16Vec { <$i as $crate::GVec>::as_i16vec(&self.$f) }
+			fn as_u16vec(&self) -> Self::U16Vec { <$i as $crate::GVec>::as_u16vec(&self.$f) }
+			fn as_ivec(&self) -> Self::I32Vec { <$i as $crate::GVec>::as_ivec(&self.$f) }
+			fn as_uvec(&self) -> Self::U32Vec { <$i as $crate::GVec>::as_uvec(&self.$f) }
+			fn as_i64vec(&self) -> Self::I64Vec { <$i as $crate::GVec>::as_i64vec(&self.$f) }
+			fn as_u64vec(&self) -> Self::U64Vec { <$i as $crate::GVec>::as_u64vec(&self.$f) }
+			fn as_usizevec(&self) -> Self::USizeVec { <$i as $crate::GVec>::as_usizevec(&self.$f) }
+			fn as_vec(&self) -> Self::F32Vec { <$i as $crate::GVec>::as_vec(&self.$f) }
+			fn as_dvec(&self) -> Self::F64Vec { <$i as $crate::GVec>::as_dvec(&self.$f) }
+			fn try_as_i8vec(&self) -> ::core::option::Option<Self::I8Vec> { <$i as $crate::GVec>::try_as_i8vec(&self.$f) }
+			fn try_as_u8vec(&self) -> ::core::option::Option<Self::U8Vec> { <$i as $crate::GVec>::try_as_u8vec(&self.$f) }
+			fn try_as_i16vec(&self) -> ::core::option::Option<Self::I16Vec> { <$i as $crate::GVec>::try_as_i16vec(&self.$f) }
+			fn try_as_u16vec(&self) -> ::core::option::Option<Self::U16Vec> { <$i as $crate::GVec>::try_as_u16vec(&self.$f) }
+			fn try_as_ivec(&self) -> ::core::option::Option<Self::I32Vec> { <$i as $crate::GVec>::try_as_ivec(&self.$f) }
+			fn try_as_uvec(&self) -> ::core::option::Option<Self::U32Vec> { <$i as $crate::GVec>::try_as_uvec(&self.$f) }
+			fn try_as_i64vec(&self) -> ::core::option::Option<Self::I64Vec> { <$i as $crate::GVec>::try_as_i64vec(&self.$f) }
+			fn try_as_u64vec(&self) -> ::core::option::Option<Self::U64Vec> { <$i as $crate::GVec>::try_as_u64vec(&self.$f) }
+			fn try_as_usizevec(&self) -> ::core::option::Option<Self::USizeVec> { <$i as $crate::GVec>::try_as_usizevec(&self.$f) }
+			fn try_as_vec(&self) -> ::core::option::Option<Self::F32Vec> { <$i as $crate::GVec>::try_as_vec(&self.$f) }
+			fn try_as_dvec(&self) -> ::core::option::Option<Self::F64Vec> { <$i as $crate::GVec>::try_as_dvec(&self.$f) }
+			fn saturating_as_i8vec(&self) -> Self::I8Vec { <$i as $crate::GVec>::saturating_as_i8vec(&self.$f) }
+			fn saturating_as_u8vec(&self) -> Self::U8Vec { <$i as $crate::GVec>::saturating_as_u8vec(&self.$f) }
+			fn saturating_as_i16vec(&self) -> Self::I16Vec { <$i as $crate::GVec>::saturating_as_i16vec(&self.$f) }
+			fn saturating_as_u16vec(&self) -> Self::U16Vec { <$i as $crate::GVec>::saturating_as_u16vec(&self.$f) }
+			fn saturating_as_ivec(&self) -> Self::I32Vec { <$i as $crate::GVec>::saturating_as_ivec(&self.$f) }
+			fn saturating_as_uvec(&self) -> Self::U32Vec { <$i as $crate::GVec>::saturating_as_uvec(&self.$f) }
+			fn saturating_as_i64vec(&self) -> Self::I64Vec { <$i as $crate::GVec>::saturating_as_i64vec(&self.$f) }
+			fn saturating_as_u64vec(&self) -> Self::U64Vec { <$i as $crate::GVec>::saturating_as_u64vec(&self.$f) }
+			fn saturating_as_usizevec(&self) -> Self::USizeVec { <$i as $crate::GVec>::saturating_as_usizevec(&self.$f) }
+			fn saturating_as_vec(&self) -> Self::F32Vec { <$i as $crate::GVec>::saturating_as_vec(&self.$f) }
+			fn saturating_as_dvec(&self) -> Self::F64Vec { <$i as $crate::GVec>::saturating_as_dvec(&self.$f) }
+		}
+	};
+	(@swizzles2 $t:ident, $i:ty, $f:tt) => {
+		impl $crate::newtype::Vec2Swizzles for $t {
+			type Vec3 = <$i as $crate::newtype::Vec2Swizzles>::Vec3;
+			type Vec4 = <$i as $crate::newtype::Vec2Swizzles>::Vec4;
+			fn xx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec2Swizzles>::xx(self.$f) } }
+			fn yx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec2Swizzles>::yx(self.$f) } }
+			fn yy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec2Swizzles>::yy(self.$f) } }
+			fn xxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::xxx(self.$f) }
+			fn xxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::xxy(self.$f) }
+			fn xyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::xyx(self.$f) }
+			fn xyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::xyy(self.$f) }
+			fn yxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::yxx(self.$f) }
+			fn yxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::yxy(self.$f) }
+			fn yyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::yyx(self.$f) }
+			fn yyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec2Swizzles>::yyy(self.$f) }
+			fn xxxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xxxx(self.$f) }
+			fn xxxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xxxy(self.$f) }
+			fn xxyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xxyx(self.$f) }
+			fn xxyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xxyy(self.$f) }
+			fn xyxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xyxx(self.$f) }
+			fn xyxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xyxy(self.$f) }
+			fn xyyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xyyx(self.$f) }
+			fn xyyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::xyyy(self.$f) }
+			fn yxxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yxxx(self.$f) }
+			fn yxxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yxxy(self.$f) }
+			fn yxyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yxyx(self.$f) }
+			fn yxyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yxyy(self.$f) }
+			fn yyxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yyxx(self.$f) }
+			fn yyxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yyxy(self.$f) }
+			fn yyyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yyyx(self.$f) }
+			fn yyyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec2Swizzles>::yyyy(self.$f) }
+		}
+	};
+	(@swizzles3 $t:ident, $i:ty, $f:tt) => {
+		impl $crate::newtype::Vec3Swizzles for $t {
+			type Vec2 = <$i as $crate::newtype::Vec3Swizzles>::Vec2;
+			type Vec4 = <$i as $crate::newtype::Vec3Swizzles>::Vec4;
+			fn xx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::xx(self.$f) }
+			fn xy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::xy(self.$f) }
+			fn with_xy(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_xy(self.$f, rhs) } }
+			fn xz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::xz(self.$f) }
+			fn with_xz(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_xz(self.$f, rhs) } }
+			fn yx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::yx(self.$f) }
+			fn with_yx(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_yx(self.$f, rhs) } }
+			fn yy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::yy(self.$f) }
+			fn yz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::yz(self.$f) }
+			fn with_yz(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_yz(self.$f, rhs) } }
+			fn zx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::zx(self.$f) }
+			fn with_zx(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_zx(self.$f, rhs) } }
+			fn zy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::zy(self.$f) }
+			fn with_zy(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::with_zy(self.$f, rhs) } }
+			fn zz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec3Swizzles>::zz(self.$f) }
+			fn xxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xxx(self.$f) } }
+			fn xxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xxy(self.$f) } }
+			fn xxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xxz(self.$f) } }
+			fn xyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xyx(self.$f) } }
+			fn xyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xyy(self.$f) } }
+			fn xzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xzx(self.$f) } }
+			fn xzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xzy(self.$f) } }
+			fn xzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::xzz(self.$f) } }
+			fn yxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yxx(self.$f) } }
+			fn yxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yxy(self.$f) } }
+			fn yxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yxz(self.$f) } }
+			fn yyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yyx(self.$f) } }
+			fn yyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yyy(self.$f) } }
+			fn yyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yyz(self.$f) } }
+			fn yzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yzx(self.$f) } }
+			fn yzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yzy(self.$f) } }
+			fn yzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::yzz(self.$f) } }
+			fn zxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zxx(self.$f) } }
+			fn zxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zxy(self.$f) } }
+			fn zxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zxz(self.$f) } }
+			fn zyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zyx(self.$f) } }
+			fn zyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zyy(self.$f) } }
+			fn zyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zyz(self.$f) } }
+			fn zzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zzx(self.$f) } }
+			fn zzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zzy(self.$f) } }
+			fn zzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec3Swizzles>::zzz(self.$f) } }
+			fn xxxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxxx(self.$f) }
+			fn xxxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxxy(self.$f) }
+			fn xxxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxxz(self.$f) }
+			fn xxyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxyx(self.$f) }
+			fn xxyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxyy(self.$f) }
+			fn xxyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxyz(self.$f) }
+			fn xxzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxzx(self.$f) }
+			fn xxzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxzy(self.$f) }
+			fn xxzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xxzz(self.$f) }
+			fn xyxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyxx(self.$f) }
+			fn xyxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyxy(self.$f) }
+			fn xyxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyxz(self.$f) }
+			fn xyyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyyx(self.$f) }
+			fn xyyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyyy(self.$f) }
+			fn xyyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyyz(self.$f) }
+			fn xyzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyzx(self.$f) }
+			fn xyzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyzy(self.$f) }
+			fn xyzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xyzz(self.$f) }
+			fn xzxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzxx(self.$f) }
+			fn xzxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzxy(self.$f) }
+			fn xzxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzxz(self.$f) }
+			fn xzyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzyx(self.$f) }
+			fn xzyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzyy(self.$f) }
+			fn xzyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzyz(self.$f) }
+			fn xzzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzzx(self.$f) }
+			fn xzzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzzy(self.$f) }
+			fn xzzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::xzzz(self.$f) }
+			fn yxxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxxx(self.$f) }
+			fn yxxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxxy(self.$f) }
+			fn yxxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxxz(self.$f) }
+			fn yxyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxyx(self.$f) }
+			fn yxyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxyy(self.$f) }
+			fn yxyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxyz(self.$f) }
+			fn yxzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxzx(self.$f) }
+			fn yxzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxzy(self.$f) }
+			fn yxzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yxzz(self.$f) }
+			fn yyxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyxx(self.$f) }
+			fn yyxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyxy(self.$f) }
+			fn yyxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyxz(self.$f) }
+			fn yyyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyyx(self.$f) }
+			fn yyyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyyy(self.$f) }
+			fn yyyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyyz(self.$f) }
+			fn yyzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyzx(self.$f) }
+			fn yyzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyzy(self.$f) }
+			fn yyzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yyzz(self.$f) }
+			fn yzxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzxx(self.$f) }
+			fn yzxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzxy(self.$f) }
+			fn yzxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzxz(self.$f) }
+			fn yzyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzyx(self.$f) }
+			fn yzyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzyy(self.$f) }
+			fn yzyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzyz(self.$f) }
+			fn yzzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzzx(self.$f) }
+			fn yzzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzzy(self.$f) }
+			fn yzzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::yzzz(self.$f) }
+			fn zxxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxxx(self.$f) }
+			fn zxxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxxy(self.$f) }
+			fn zxxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxxz(self.$f) }
+			fn zxyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxyx(self.$f) }
+			fn zxyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxyy(self.$f) }
+			fn zxyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxyz(self.$f) }
+			fn zxzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxzx(self.$f) }
+			fn zxzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxzy(self.$f) }
+			fn zxzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zxzz(self.$f) }
+			fn zyxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyxx(self.$f) }
+			fn zyxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyxy(self.$f) }
+			fn zyxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyxz(self.$f) }
+			fn zyyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyyx(self.$f) }
+			fn zyyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyyy(self.$f) }
+			fn zyyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyyz(self.$f) }
+			fn zyzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyzx(self.$f) }
+			fn zyzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyzy(self.$f) }
+			fn zyzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zyzz(self.$f) }
+			fn zzxx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzxx(self.$f) }
+			fn zzxy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzxy(self.$f) }
+			fn zzxz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzxz(self.$f) }
+			fn zzyx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzyx(self.$f) }
+			fn zzyy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzyy(self.$f) }
+			fn zzyz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzyz(self.$f) }
+			fn zzzx(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzzx(self.$f) }
+			fn zzzy(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzzy(self.$f) }
+			fn zzzz(self) -> Self::Vec4 { <$i as $crate::newtype::Vec3Swizzles>::zzzz(self.$f) }
+		}
+	};
+	(@swizzles4 $t:ident, $i:ty, $f:tt) => {
+		impl $crate::newtype::Vec4Swizzles for $t {
+			type Vec2 = <$i as $crate::newtype::Vec4Swizzles>::Vec2;
+			type Vec3 = <$i as $crate::newtype::Vec4Swizzles>::Vec3;
+			fn xx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::xx(self.$f) }
+			fn xy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::xy(self.$f) }
+			fn with_xy(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xy(self.$f, rhs) } }
+			fn xz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::xz(self.$f) }
+			fn with_xz(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xz(self.$f, rhs) } }
+			fn xw(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::xw(self.$f) }
+			fn with_xw(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xw(self.$f, rhs) } }
+			fn yx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::yx(self.$f) }
+			fn with_yx(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yx(self.$f, rhs) } }
+			fn yy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::yy(self.$f) }
+			fn yz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::yz(self.$f) }
+			fn with_yz(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yz(self.$f, rhs) } }
+			fn yw(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::yw(self.$f) }
+			fn with_yw(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yw(self.$f, rhs) } }
+			fn zx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::zx(self.$f) }
+			fn with_zx(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zx(self.$f, rhs) } }
+			fn zy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::zy(self.$f) }
+			fn with_zy(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zy(self.$f, rhs) } }
+			fn zz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::zz(self.$f) }
+			fn zw(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::zw(self.$f) }
+			fn with_zw(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zw(self.$f, rhs) } }
+			fn wx(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::wx(self.$f) }
+			fn with_wx(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wx(self.$f, rhs) } }
+			fn wy(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::wy(self.$f) }
+			fn with_wy(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wy(self.$f, rhs) } }
+			fn wz(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::wz(self.$f) }
+			fn with_wz(self, rhs: Self::Vec2) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wz(self.$f, rhs) } }
+			fn ww(self) -> Self::Vec2 { <$i as $crate::newtype::Vec4Swizzles>::ww(self.$f) }
+			fn xxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xxx(self.$f) }
+			fn xxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xxy(self.$f) }
+			fn xxz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xxz(self.$f) }
+			fn xxw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xxw(self.$f) }
+			fn xyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xyx(self.$f) }
+			fn xyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xyy(self.$f) }
+			fn xyz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xyz(self.$f) }
+			fn with_xyz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xyz(self.$f, rhs) } }
+			fn xyw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xyw(self.$f) }
+			fn with_xyw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xyw(self.$f, rhs) } }
+			fn xzx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xzx(self.$f) }
+			fn xzy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xzy(self.$f) }
+			fn with_xzy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xzy(self.$f, rhs) } }
+			fn xzz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xzz(self.$f) }
+			fn xzw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xzw(self.$f) }
+			fn with_xzw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xzw(self.$f, rhs) } }
+			fn xwx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xwx(self.$f) }
+			fn xwy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xwy(self.$f) }
+			fn with_xwy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xwy(self.$f, rhs) } }
+			fn xwz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xwz(self.$f) }
+			fn with_xwz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_xwz(self.$f, rhs) } }
+			fn xww(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::xww(self.$f) }
+			fn yxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yxx(self.$f) }
+			fn yxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yxy(self.$f) }
+			fn yxz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yxz(self.$f) }
+			fn with_yxz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yxz(self.$f, rhs) } }
+			fn yxw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yxw(self.$f) }
+			fn with_yxw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yxw(self.$f, rhs) } }
+			fn yyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yyx(self.$f) }
+			fn yyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yyy(self.$f) }
+			fn yyz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yyz(self.$f) }
+			fn yyw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yyw(self.$f) }
+			fn yzx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yzx(self.$f) }
+			fn with_yzx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yzx(self.$f, rhs) } }
+			fn yzy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yzy(self.$f) }
+			fn yzz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yzz(self.$f) }
+			fn yzw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yzw(self.$f) }
+			fn with_yzw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_yzw(self.$f, rhs) } }
+			fn ywx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::ywx(self.$f) }
+			fn with_ywx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_ywx(self.$f, rhs) } }
+			fn ywy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::ywy(self.$f) }
+			fn ywz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::ywz(self.$f) }
+			fn with_ywz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_ywz(self.$f, rhs) } }
+			fn yww(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::yww(self.$f) }
+			fn zxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zxx(self.$f) }
+			fn zxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zxy(self.$f) }
+			fn with_zxy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zxy(self.$f, rhs) } }
+			fn zxz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zxz(self.$f) }
+			fn zxw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zxw(self.$f) }
+			fn with_zxw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zxw(self.$f, rhs) } }
+			fn zyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zyx(self.$f) }
+			fn with_zyx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zyx(self.$f, rhs) } }
+			fn zyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zyy(self.$f) }
+			fn zyz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zyz(self.$f) }
+			fn zyw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zyw(self.$f) }
+			fn with_zyw(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zyw(self.$f, rhs) } }
+			fn zzx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zzx(self.$f) }
+			fn zzy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zzy(self.$f) }
+			fn zzz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zzz(self.$f) }
+			fn zzw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zzw(self.$f) }
+			fn zwx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zwx(self.$f) }
+			fn with_zwx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zwx(self.$f, rhs) } }
+			fn zwy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zwy(self.$f) }
+			fn with_zwy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_zwy(self.$f, rhs) } }
+			fn zwz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zwz(self.$f) }
+			fn zww(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::zww(self.$f) }
+			fn wxx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wxx(self.$f) }
+			fn wxy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wxy(self.$f) }
+			fn with_wxy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wxy(self.$f, rhs) } }
+			fn wxz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wxz(self.$f) }
+			fn with_wxz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wxz(self.$f, rhs) } }
+			fn wxw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wxw(self.$f) }
+			fn wyx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wyx(self.$f) }
+			fn with_wyx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wyx(self.$f, rhs) } }
+			fn wyy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wyy(self.$f) }
+			fn wyz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wyz(self.$f) }
+			fn with_wyz(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wyz(self.$f, rhs) } }
+			fn wyw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wyw(self.$f) }
+			fn wzx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wzx(self.$f) }
+			fn with_wzx(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wzx(self.$f, rhs) } }
+			fn wzy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wzy(self.$f) }
+			fn with_wzy(self, rhs: Self::Vec3) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::with_wzy(self.$f, rhs) } }
+			fn wzz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wzz(self.$f) }
+			fn wzw(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wzw(self.$f) }
+			fn wwx(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wwx(self.$f) }
+			fn wwy(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wwy(self.$f) }
+			fn wwz(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::wwz(self.$f) }
+			fn www(self) -> Self::Vec3 { <$i as $crate::newtype::Vec4Swizzles>::www(self.$f) }
+			fn xxxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxxx(self.$f) } }
+			fn xxxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxxy(self.$f) } }
+			fn xxxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxxz(self.$f) } }
+			fn xxxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxxw(self.$f) } }
+			fn xxyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxyx(self.$f) } }
+			fn xxyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxyy(self.$f) } }
+			fn xxyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxyz(self.$f) } }
+			fn xxyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxyw(self.$f) } }
+			fn xxzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxzx(self.$f) } }
+			fn xxzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxzy(self.$f) } }
+			fn xxzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxzz(self.$f) } }
+			fn xxzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxzw(self.$f) } }
+			fn xxwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxwx(self.$f) } }
+			fn xxwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxwy(self.$f) } }
+			fn xxwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxwz(self.$f) } }
+			fn xxww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xxww(self.$f) } }
+			fn xyxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyxx(self.$f) } }
+			fn xyxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyxy(self.$f) } }
+			fn xyxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyxz(self.$f) } }
+			fn xyxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyxw(self.$f) } }
+			fn xyyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyyx(self.$f) } }
+			fn xyyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyyy(self.$f) } }
+			fn xyyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyyz(self.$f) } }
+			fn xyyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyyw(self.$f) } }
+			fn xyzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyzx(self.$f) } }
+			fn xyzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyzy(self.$f) } }
+			fn xyzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyzz(self.$f) } }
+			fn xywx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xywx(self.$f) } }
+			fn xywy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xywy(self.$f) } }
+			fn xywz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xywz(self.$f) } }
+			fn xyww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xyww(self.$f) } }
+			fn xzxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzxx(self.$f) } }
+			fn xzxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzxy(self.$f) } }
+			fn xzxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzxz(self.$f) } }
+			fn xzxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzxw(self.$f) } }
+			fn xzyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzyx(self.$f) } }
+			fn xzyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzyy(self.$f) } }
+			fn xzyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzyz(self.$f) } }
+			fn xzyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzyw(self.$f) } }
+			fn xzzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzzx(self.$f) } }
+			fn xzzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzzy(self.$f) } }
+			fn xzzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzzz(self.$f) } }
+			fn xzzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzzw(self.$f) } }
+			fn xzwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzwx(self.$f) } }
+			fn xzwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzwy(self.$f) } }
+			fn xzwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzwz(self.$f) } }
+			fn xzww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xzww(self.$f) } }
+			fn xwxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwxx(self.$f) } }
+			fn xwxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwxy(self.$f) } }
+			fn xwxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwxz(self.$f) } }
+			fn xwxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwxw(self.$f) } }
+			fn xwyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwyx(self.$f) } }
+			fn xwyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwyy(self.$f) } }
+			fn xwyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwyz(self.$f) } }
+			fn xwyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwyw(self.$f) } }
+			fn xwzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwzx(self.$f) } }
+			fn xwzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwzy(self.$f) } }
+			fn xwzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwzz(self.$f) } }
+			fn xwzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwzw(self.$f) } }
+			fn xwwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwwx(self.$f) } }
+			fn xwwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwwy(self.$f) } }
+			fn xwwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwwz(self.$f) } }
+			fn xwww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::xwww(self.$f) } }
+			fn yxxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxxx(self.$f) } }
+			fn yxxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxxy(self.$f) } }
+			fn yxxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxxz(self.$f) } }
+			fn yxxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxxw(self.$f) } }
+			fn yxyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxyx(self.$f) } }
+			fn yxyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxyy(self.$f) } }
+			fn yxyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxyz(self.$f) } }
+			fn yxyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxyw(self.$f) } }
+			fn yxzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxzx(self.$f) } }
+			fn yxzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxzy(self.$f) } }
+			fn yxzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxzz(self.$f) } }
+			fn yxzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxzw(self.$f) } }
+			fn yxwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxwx(self.$f) } }
+			fn yxwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxwy(self.$f) } }
+			fn yxwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxwz(self.$f) } }
+			fn yxww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yxww(self.$f) } }
+			fn yyxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyxx(self.$f) } }
+			fn yyxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyxy(self.$f) } }
+			fn yyxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyxz(self.$f) } }
+			fn yyxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyxw(self.$f) } }
+			fn yyyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyyx(self.$f) } }
+			fn yyyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyyy(self.$f) } }
+			fn yyyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyyz(self.$f) } }
+			fn yyyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyyw(self.$f) } }
+			fn yyzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyzx(self.$f) } }
+			fn yyzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyzy(self.$f) } }
+			fn yyzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyzz(self.$f) } }
+			fn yyzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyzw(self.$f) } }
+			fn yywx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yywx(self.$f) } }
+			fn yywy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yywy(self.$f) } }
+			fn yywz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yywz(self.$f) } }
+			fn yyww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yyww(self.$f) } }
+			fn yzxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzxx(self.$f) } }
+			fn yzxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzxy(self.$f) } }
+			fn yzxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzxz(self.$f) } }
+			fn yzxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzxw(self.$f) } }
+			fn yzyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzyx(self.$f) } }
+			fn yzyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzyy(self.$f) } }
+			fn yzyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzyz(self.$f) } }
+			fn yzyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzyw(self.$f) } }
+			fn yzzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzzx(self.$f) } }
+			fn yzzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzzy(self.$f) } }
+			fn yzzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzzz(self.$f) } }
+			fn yzzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzzw(self.$f) } }
+			fn yzwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzwx(self.$f) } }
+			fn yzwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzwy(self.$f) } }
+			fn yzwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzwz(self.$f) } }
+			fn yzww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::yzww(self.$f) } }
+			fn ywxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywxx(self.$f) } }
+			fn ywxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywxy(self.$f) } }
+			fn ywxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywxz(self.$f) } }
+			fn ywxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywxw(self.$f) } }
+			fn ywyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywyx(self.$f) } }
+			fn ywyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywyy(self.$f) } }
+			fn ywyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywyz(self.$f) } }
+			fn ywyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywyw(self.$f) } }
+			fn ywzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywzx(self.$f) } }
+			fn ywzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywzy(self.$f) } }
+			fn ywzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywzz(self.$f) } }
+			fn ywzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywzw(self.$f) } }
+			fn ywwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywwx(self.$f) } }
+			fn ywwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywwy(self.$f) } }
+			fn ywwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywwz(self.$f) } }
+			fn ywww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::ywww(self.$f) } }
+			fn zxxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxxx(self.$f) } }
+			fn zxxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxxy(self.$f) } }
+			fn zxxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxxz(self.$f) } }
+			fn zxxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxxw(self.$f) } }
+			fn zxyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxyx(self.$f) } }
+			fn zxyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxyy(self.$f) } }
+			fn zxyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxyz(self.$f) } }
+			fn zxyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxyw(self.$f) } }
+			fn zxzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxzx(self.$f) } }
+			fn zxzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxzy(self.$f) } }
+			fn zxzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxzz(self.$f) } }
+			fn zxzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxzw(self.$f) } }
+			fn zxwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxwx(self.$f) } }
+			fn zxwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxwy(self.$f) } }
+			fn zxwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxwz(self.$f) } }
+			fn zxww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zxww(self.$f) } }
+			fn zyxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyxx(self.$f) } }
+			fn zyxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyxy(self.$f) } }
+			fn zyxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyxz(self.$f) } }
+			fn zyxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyxw(self.$f) } }
+			fn zyyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyyx(self.$f) } }
+			fn zyyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyyy(self.$f) } }
+			fn zyyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyyz(self.$f) } }
+			fn zyyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyyw(self.$f) } }
+			fn zyzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyzx(self.$f) } }
+			fn zyzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyzy(self.$f) } }
+			fn zyzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyzz(self.$f) } }
+			fn zyzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyzw(self.$f) } }
+			fn zywx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zywx(self.$f) } }
+			fn zywy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zywy(self.$f) } }
+			fn zywz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zywz(self.$f) } }
+			fn zyww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zyww(self.$f) } }
+			fn zzxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzxx(self.$f) } }
+			fn zzxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzxy(self.$f) } }
+			fn zzxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzxz(self.$f) } }
+			fn zzxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzxw(self.$f) } }
+			fn zzyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzyx(self.$f) } }
+			fn zzyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzyy(self.$f) } }
+			fn zzyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzyz(self.$f) } }
+			fn zzyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzyw(self.$f) } }
+			fn zzzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzzx(self.$f) } }
+			fn zzzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzzy(self.$f) } }
+			fn zzzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzzz(self.$f) } }
+			fn zzzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzzw(self.$f) } }
+			fn zzwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzwx(self.$f) } }
+			fn zzwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzwy(self.$f) } }
+			fn zzwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzwz(self.$f) } }
+			fn zzww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zzww(self.$f) } }
+			fn zwxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwxx(self.$f) } }
+			fn zwxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwxy(self.$f) } }
+			fn zwxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwxz(self.$f) } }
+			fn zwxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwxw(self.$f) } }
+			fn zwyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwyx(self.$f) } }
+			fn zwyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwyy(self.$f) } }
+			fn zwyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwyz(self.$f) } }
+			fn zwyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwyw(self.$f) } }
+			fn zwzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwzx(self.$f) } }
+			fn zwzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwzy(self.$f) } }
+			fn zwzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwzz(self.$f) } }
+			fn zwzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwzw(self.$f) } }
+			fn zwwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwwx(self.$f) } }
+			fn zwwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwwy(self.$f) } }
+			fn zwwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwwz(self.$f) } }
+			fn zwww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::zwww(self.$f) } }
+			fn wxxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxxx(self.$f) } }
+			fn wxxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxxy(self.$f) } }
+			fn wxxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxxz(self.$f) } }
+			fn wxxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxxw(self.$f) } }
+			fn wxyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxyx(self.$f) } }
+			fn wxyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxyy(self.$f) } }
+			fn wxyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxyz(self.$f) } }
+			fn wxyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxyw(self.$f) } }
+			fn wxzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxzx(self.$f) } }
+			fn wxzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxzy(self.$f) } }
+			fn wxzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxzz(self.$f) } }
+			fn wxzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxzw(self.$f) } }
+			fn wxwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxwx(self.$f) } }
+			fn wxwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxwy(self.$f) } }
+			fn wxwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxwz(self.$f) } }
+			fn wxww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wxww(self.$f) } }
+			fn wyxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyxx(self.$f) } }
+			fn wyxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyxy(self.$f) } }
+			fn wyxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyxz(self.$f) } }
+			fn wyxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyxw(self.$f) } }
+			fn wyyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyyx(self.$f) } }
+			fn wyyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyyy(self.$f) } }
+			fn wyyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyyz(self.$f) } }
+			fn wyyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyyw(self.$f) } }
+			fn wyzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyzx(self.$f) } }
+			fn wyzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyzy(self.$f) } }
+			fn wyzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyzz(self.$f) } }
+			fn wyzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyzw(self.$f) } }
+			fn wywx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wywx(self.$f) } }
+			fn wywy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wywy(self.$f) } }
+			fn wywz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wywz(self.$f) } }
+			fn wyww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wyww(self.$f) } }
+			fn wzxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzxx(self.$f) } }
+			fn wzxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzxy(self.$f) } }
+			fn wzxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzxz(self.$f) } }
+			fn wzxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzxw(self.$f) } }
+			fn wzyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzyx(self.$f) } }
+			fn wzyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzyy(self.$f) } }
+			fn wzyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzyz(self.$f) } }
+			fn wzyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzyw(self.$f) } }
+			fn wzzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzzx(self.$f) } }
+			fn wzzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzzy(self.$f) } }
+			fn wzzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzzz(self.$f) } }
+			fn wzzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzzw(self.$f) } }
+			fn wzwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzwx(self.$f) } }
+			fn wzwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzwy(self.$f) } }
+			fn wzwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzwz(self.$f) } }
+			fn wzww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wzww(self.$f) } }
+			fn wwxx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwxx(self.$f) } }
+			fn wwxy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwxy(self.$f) } }
+			fn wwxz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwxz(self.$f) } }
+			fn wwxw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwxw(self.$f) } }
+			fn wwyx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwyx(self.$f) } }
+			fn wwyy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwyy(self.$f) } }
+			fn wwyz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwyz(self.$f) } }
+			fn wwyw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwyw(self.$f) } }
+			fn wwzx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwzx(self.$f) } }
+			fn wwzy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwzy(self.$f) } }
+			fn wwzz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwzz(self.$f) } }
+			fn wwzw(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwzw(self.$f) } }
+			fn wwwx(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwwx(self.$f) } }
+			fn wwwy(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwwy(self.$f) } }
+			fn wwwz(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwwz(self.$f) } }
+			fn wwww(self) -> Self { Self { $f: <$i as $crate::newtype::Vec4Swizzles>::wwww(self.$f) } }
+		}
+	};
+	(GVec2, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@swizzles2 $t, $i, $f);
+		$crate::__newtype!(@tuple $t, $f, ($s, $s));
+		$crate::__newtype_mint! {
+			$crate::__newtype!(@tuple $t, $f, $crate::newtype::mint::Vector2<$s>);
+			$crate::__newtype!(@tuple $t, $f, $crate::newtype::mint::Point2<$s>);
+		}
+		impl $crate::GVec2 for $t {
+			type Extended = <$i as $crate::GVec2>::Extended;
+			const X: Self = Self { $f: <$i as $crate::GVec2>::X };
+			const Y: Self = Self { $f: <$i as $crate::GVec2>::Y };
+			fn x(self) -> Self::Scalar { <$i as $crate::GVec2>::x(self.$f) }
+			fn y(self) -> Self::Scalar { <$i as $crate::GVec2>::y(self.$f) }
+			fn new(x: Self::Scalar, y: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec2>::new(x, y) } }
+			fn extend(self, z: Self::Scalar) -> Self::Extended { <$i as $crate::GVec2>::extend(self.$f, z) }
+			fn with_x(self, x: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec2>::with_x(self.$f, x) } }
+			fn with_y(self, y: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec2>::with_y(self.$f, y) } }
+		}
+	};
+	(GVec3, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@swizzles3 $t, $i, $f);
+		$crate::__newtype!(@tuple $t, $f, ($s, $s, $s));
+		$crate::__newtype_mint! {
+			$crate::__newtype!(@tuple $t, $f, $crate::newtype::mint::Vector3<$s>);
+			$crate::__newtype!(@tuple $t, $f, $crate::newtype::mint::Point3<$s>);
+		}
+		impl $crate::GVec3 for $t {
+			type Extended = <$i as $crate::GVec3>::Extended;
+			type Truncated = <$i as $crate::GVec3>::Truncated;
+			const X: Self = Self { $f: <$i as $crate::GVec3>::X };
+			const Y: Self = Self { $f: <$i as $crate::GVec3>::Y };
+			const Z: Self = Self { $f: <$i as $crate::GVec3>::Z };
+			fn x(self) -> Self::Scalar { <$i as $crate::GVec3>::x(self.$f) }
+			fn y(self) -> Self::Scalar { <$i as $crate::GVec3>::y(self.$f) }
+			fn z(self) -> Self::Scalar { <$i as $crate::GVec3>::z(self.$f) }
+			fn new(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec3>::new(x, y, z) } }
+			fn extend(self, w: Self::Scalar) -> Self::Extended { <$i as $crate::GVec3>::extend(self.$f, w) }
+			fn truncate(self) -> Self::Truncated { <$i as $crate::GVec3>::truncate(self.$f) }
+			fn with_x(self, x: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec3>::with_x(self.$f, x) } }
+			fn with_y(self, y: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec3>::with_y(self.$f, y) } }
+			fn with_z(self, z: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec3>::with_z(self.$f, z) } }
+			fn cross(self, rhs: Self) -> Self { Self { $f: <$i as $crate::GVec3>::cross(self.$f, rhs.$f) } }
+		}
+	};
+	(GVec4, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@swizzles4 $t, $i, $f);
+		$crate::__newtype!(@tuple $t, $f, ($s, $s, $s, $s));
+		$crate::__newtype_mint! {
+			$crate::__newtype!(@tuple $t, $f, $crate::newtype::mint::Vector4<$s>);
+		}
+		impl $crate::GVec4 for $t {
+			type Truncated = <$i as $crate::GVec4>::Truncated;
+			const X: Self = Self { $f: <$i as $crate::GVec4>::X };
+			const Y: Self = Self { $f: <$i as $crate::GVec4>::Y };
+			const Z: Self = Self { $f: <$i as $crate::GVec4>::Z };
+			const W: Self = Self { $f: <$i as $crate::GVec4>::W };
+			fn x(self) -> Self::Scalar { <$i as $crate::GVec4>::x(self.$f) }
+			fn y(self) -> Self::Scalar { <$i as $crate::GVec4>::y(self.$f) }
+			fn z(self) -> Self::Scalar { <$i as $crate::GVec4>::z(self.$f) }
+			fn w(self) -> Self::Scalar { <$i as $crate::GVec4>::w(self.$f) }
+			fn new(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec4>::new(x, y, z, w) } }
+			fn truncate(self) -> Self::Truncated { <$i as $crate::GVec4>::truncate(self.$f) }
+			fn with_x(self, x: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec4>::with_x(self.$f, x) } }
+			fn with_y(self, y: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec4>::with_y(self.$f, y) } }
+			fn with_z(self, z: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec4>::with_z(self.$f, z) } }
+			fn with_w(self, w: Self::Scalar) -> Self { Self { $f: <$i as $crate::GVec4>::with_w(self.$f, w) } }
+		}
+	};
+	(SignedVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl ::core::ops::Neg for $t {
+			type Output = Self;
+			fn neg(self) -> Self { Self { $f: ::core::ops::Neg::neg(self.$f) } }
+		}
+		impl $crate::SignedVec for $t {
+			const NEG_ONE: Self = Self { $f: <$i as $crate::SignedVec>::NEG_ONE };
+			fn abs(self) -> Self { Self { $f: <$i as $crate::SignedVec>::abs(self.$f) } }
+			fn signum(self) -> Self { Self { $f: <$i as $crate::SignedVec>::signum(self.$f) } }
+			fn is_negative_bitmask(self) -> u32 { <$i as $crate::SignedVec>::is_negative_bitmask(self.$f) }
+			fn distance_squared(self, rhs: Self) -> Self::Scalar { <$i as $crate::SignedVec>::distance_squared(self.$f, rhs.$f) }
+			fn div_euclid(self, rhs: Self) -> Self { Self { $f: <$i as $crate::SignedVec>::div_euclid(self.$f, rhs.$f) } }
+			fn rem_euclid(self, rhs: Self) -> Self { Self { $f: <$i as $crate::SignedVec>::rem_euclid(self.$f, rhs.$f) } }
+		}
+	};
+	(SignedVec2, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::SignedVec2 for $t {
+			const NEG_X: Self = Self { $f: <$i as $crate::SignedVec2>::NEG_X };
+			const NEG_Y: Self = Self { $f: <$i as $crate::SignedVec2>::NEG_Y };
+			fn perp(self) -> Self { Self { $f: <$i as $crate::SignedVec2>::perp(self.$f) } }
+			fn perp_dot(self, rhs: Self) -> Self::Scalar { <$i as $crate::SignedVec2>::perp_dot(self.$f, rhs.$f) }
+			fn rotate(self, rhs: Self) -> Self { Self { $f: <$i as $crate::SignedVec2>::rotate(self.$f, rhs.$f) } }
+		}
+	};
+	(SignedVec3, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::SignedVec3 for $t {
+			const NEG_X: Self = Self { $f: <$i as $crate::SignedVec3>::NEG_X };
+			const NEG_Y: Self = Self { $f: <$i as $crate::SignedVec3>::NEG_Y };
+			const NEG_Z: Self = Self { $f: <$i as $crate::SignedVec3>::NEG_Z };
+		}
+	};
+	(SignedVec4, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::SignedVec4 for $t {
+			const NEG_X: Self = Self { $f: <$i as $crate::SignedVec4>::NEG_X };
+			const NEG_Y: Self = Self { $f: <$i as $crate::SignedVec4>::NEG_Y };
+			const NEG_Z: Self = Self { $f: <$i as $crate::SignedVec4>::NEG_Z };
+			const NEG_W: Self = Self { $f: <$i as $crate::SignedVec4>::NEG_W };
+		}
+	};
+	(FloatVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype_approx! {
+			impl $crate::newtype::approx::AbsDiffEq for $t {
+				type Epsilon = $s;
+				fn default_epsilon() -> $s { <$i as $crate::newtype::approx::AbsDiffEq>::default_epsilon() }
+				fn abs_diff_eq(&self, other: &Self, epsilon: $s) -> bool { <$i as $crate::newtype::approx::AbsDiffEq>::abs_diff_eq(&self.$f, &other.$f, epsilon) }
+			}
+			impl $crate::newtype::approx::RelativeEq for $t {
+				fn default_max_relative() -> $s { <$i as $crate::newtype::approx::RelativeEq>::default_max_relative() }
+				fn relative_eq(&self, other: &Self, epsilon: $s, max_relative: $s) -> bool {
+					<$i as $crate::newtype::approx::RelativeEq>::relative_eq(&self.$f, &other.$f, epsilon, max_relative)
+				}
+			}
+			impl $crate::newtype::approx::UlpsEq for $t {
+				fn default_max_ulps() -> u32 { <$i as $crate::newtype::approx::UlpsEq>::default_max_ulps() }
+				fn ulps_eq(&self, other: &Self, epsilon: $s, max_ulps: u32) -> bool {
+					<$i as $crate::newtype::approx::UlpsEq>::ulps_eq(&self.$f, &other.$f, epsilon, max_ulps)
+				}
+			}
+		}
+		impl $crate::FloatVec for $t {
+			const NAN: Self = Self { $f: <$i as $crate::FloatVec>::NAN };
+			const INFINITY: Self = Self { $f: <$i as $crate::FloatVec>::INFINITY };
+			const NEG_INFINITY: Self = Self { $f: <$i as $crate::FloatVec>::NEG_INFINITY };
+			fn copysign(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::copysign(self.$f, rhs.$f) } }
+			fn is_finite(self) -> bool { <$i as $crate::FloatVec>::is_finite(self.$f) }
+			fn is_finite_mask(self) -> Self::BVec { <$i as $crate::FloatVec>::is_finite_mask(self.$f) }
+			fn is_nan(self) -> bool { <$i as $crate::FloatVec>::is_nan(self.$f) }
+			fn is_nan_mask(self) -> Self::BVec { <$i as $crate::FloatVec>::is_nan_mask(self.$f) }
+			fn length(self) -> Self::Scalar { <$i as $crate::FloatVec>::length(self.$f) }
+			fn length_recip(self) -> Self::Scalar { <$i as $crate::FloatVec>::length_recip(self.$f) }
+			fn distance(self, rhs: Self) -> Self::Scalar { <$i as $crate::FloatVec>::distance(self.$f, rhs.$f) }
+			fn normalize(self) -> Self { Self { $f: <$i as $crate::FloatVec>::normalize(self.$f) } }
+			fn try_normalize(self) -> ::core::option::Option<Self> { <$i as $crate::FloatVec>::try_normalize(self.$f).map(|v| Self { $f: v }) }
+			fn normalize_or(self, fallback: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::normalize_or(self.$f, fallback.$f) } }
+			fn normalize_or_zero(self) -> Self { Self { $f: <$i as $crate::FloatVec>::normalize_or_zero(self.$f) } }
+			fn normalize_and_length(self) -> (Self, Self::Scalar) { let (v, s) = <$i as $crate::FloatVec>::normalize_and_length(self.$f); (Self { $f: v }, s) }
+			fn is_normalized(self) -> bool { <$i as $crate::FloatVec>::is_normalized(self.$f) }
+			fn project_onto(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::project_onto(self.$f, rhs.$f) } }
+			fn reject_from(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::reject_from(self.$f, rhs.$f) } }
+			fn project_onto_normalized(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::project_onto_normalized(self.$f, rhs.$f) } }
+			fn reject_from_normalized(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::reject_from_normalized(self.$f, rhs.$f) } }
+			fn round(self) -> Self { Self { $f: <$i as $crate::FloatVec>::round(self.$f) } }
+			fn floor(self) -> Self { Self { $f: <$i as $crate::FloatVec>::floor(self.$f) } }
+			fn ceil(self) -> Self { Self { $f: <$i as $crate::FloatVec>::ceil(self.$f) } }
+			fn trunc(self) -> Self { Self { $f: <$i as $crate::FloatVec>::trunc(self.$f) } }
+			fn fract(self) -> Self { Self { $f: <$i as $crate::FloatVec>::fract(self.$f) } }
+			fn fract_gl(self) -> Self { Self { $f: <$i as $crate::FloatVec>::fract_gl(self.$f) } }
+			fn exp(self) -> Self { Self { $f: <$i as $crate::FloatVec>::exp(self.$f) } }
+			fn exp2(self) -> Self { Self { $f: <$i as $crate::FloatVec>::exp2(self.$f) } }
+			fn ln(self) -> Self { Self { $f: <$i as $crate::FloatVec>::ln(self.$f) } }
+			fn log2(self) -> Self { Self { $f: <$i as $crate::FloatVec>::log2(self.$f) } }
+			fn powf(self, n: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::powf(self.$f, n) } }
+			fn sqrt(self) -> Self { Self { $f: <$i as $crate::FloatVec>::sqrt(self.$f) } }
+			fn cbrt(self) -> Self { Self { $f: <$i as $crate::FloatVec>::cbrt(self.$f) } }
+			fn hypot(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::hypot(self.$f, rhs.$f) } }
+			fn sin(self) -> Self { Self { $f: <$i as $crate::FloatVec>::sin(self.$f) } }
+			fn cos(self) -> Self { Self { $f: <$i as $crate::FloatVec>::cos(self.$f) } }
+			fn tan(self) -> Self { Self { $f: <$i as $crate::FloatVec>::tan(self.$f) } }
+			fn asin(self) -> Self { Self { $f: <$i as $crate::FloatVec>::asin(self.$f) } }
+			fn acos(self) -> Self { Self { $f: <$i as $crate::FloatVec>::acos(self.$f) } }
+			fn atan2(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::atan2(self.$f, rhs.$f) } }
+			fn sin_cos(self) -> (Self, Self) {
+				let (s, c) = <$i as $crate::FloatVec>::sin_cos(self.$f);
+				(Self { $f: s }, Self { $f: c })
+			}
+			fn recip(self) -> Self { Self { $f: <$i as $crate::FloatVec>::recip(self.$f) } }
+			fn step(self, edge: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::step(self.$f, edge.$f) } }
+			fn smoothstep(self, edge0: Self, edge1: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::smoothstep(self.$f, edge0.$f, edge1.$f) } }
+			fn saturate(self) -> Self { Self { $f: <$i as $crate::FloatVec>::saturate(self.$f) } }
+			fn lerp(self, rhs: Self, s: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::lerp(self.$f, rhs.$f, s) } }
+			fn move_towards(&self, rhs: Self, d: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::move_towards(&self.$f, rhs.$f, d) } }
+			fn midpoint(self, rhs: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::midpoint(self.$f, rhs.$f) } }
+			fn abs_diff_eq(self, rhs: Self, max_abs_diff: Self::Scalar) -> bool { <$i as $crate::FloatVec>::abs_diff_eq(self.$f, rhs.$f, max_abs_diff) }
+			$crate::__newtype_approx! {
+				fn relative_eq(self, rhs: Self, epsilon: Self::Scalar, max_relative: Self::Scalar) -> bool { <$i as $crate::FloatVec>::relative_eq(self.$f, rhs.$f, epsilon, max_relative) }
+				fn ulps_eq(self, rhs: Self, epsilon: Self::Scalar, max_ulps: u32) -> bool { <$i as $crate::FloatVec>::ulps_eq(self.$f, rhs.$f, epsilon, max_ulps) }
+			}
+			fn clamp_length(self, min: Self::Scalar, max: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::clamp_length(self.$f, min, max) } }
+			fn clamp_length_max(self, max: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::clamp_length_max(self.$f, max) } }
+			fn clamp_length_min(self, min: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::clamp_length_min(self.$f, min) } }
+			fn mul_add(self, a: Self, b: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::mul_add(self.$f, a.$f, b.$f) } }
+			fn reflect(self, normal: Self) -> Self { Self { $f: <$i as $crate::FloatVec>::reflect(self.$f, normal.$f) } }
+			fn refract(self, normal: Self, eta: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec>::refract(self.$f, normal.$f, eta) } }
+		}
+	};
+	(FloatVec2, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::FloatVec2 for $t {
+			type Affine = <$i as $crate::FloatVec2>::Affine;
+			fn angle_to(self, rhs: Self) -> Self::Scalar { <$i as $crate::FloatVec2>::angle_to(self.$f, rhs.$f) }
+			fn from_angle(angle: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec2>::from_angle(angle) } }
+			fn to_angle(self) -> Self::Scalar { <$i as $crate::FloatVec2>::to_angle(self.$f) }
+			fn rotate_towards(&self, rhs: Self, max_angle: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec2>::rotate_towards(&self.$f, rhs.$f, max_angle) } }
+		}
+	};
+	(FloatVec3, $t:ident, $i:ty, $f:tt, $s:ty, $quat:ident $(, $counterpart:ident)?) => {
+		impl ::core::ops::Mul<$t> for $crate::newtype::$quat {
+			type Output = $t;
+			fn mul(self, rhs: $t) -> $t { $t { $f: ::core::ops::Mul::mul(self, rhs.$f) } }
+		}
+		$(
+			impl ::core::convert::From<$t> for $crate::newtype::$counterpart {
+				fn from(v: $t) -> Self { ::core::convert::From::from(v.$f) }
+			}
+		)?
+		impl $crate::FloatVec3 for $t {
+			type Quat = <$i as $crate::FloatVec3>::Quat;
+			type Affine = <$i as $crate::FloatVec3>::Affine;
+			fn angle_between(self, rhs: Self) -> Self::Scalar { <$i as $crate::FloatVec3>::angle_between(self.$f, rhs.$f) }
+			fn any_orthogonal_vector(&self) -> Self { Self { $f: <$i as $crate::FloatVec3>::any_orthogonal_vector(&self.$f) } }
+			fn any_orthonormal_vector(&self) -> Self { Self { $f: <$i as $crate::FloatVec3>::any_orthonormal_vector(&self.$f) } }
+			fn any_orthonormal_pair(&self) -> (Self, Self) { let (a, b) = <$i as $crate::FloatVec3>::any_orthonormal_pair(&self.$f); (Self { $f: a }, Self { $f: b }) }
+			fn rotate_towards(self, rhs: Self, max_angle: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec3>::rotate_towards(self.$f, rhs.$f, max_angle) } }
+			fn slerp(self, rhs: Self, s: Self::Scalar) -> Self { Self { $f: <$i as $crate::FloatVec3>::slerp(self.$f, rhs.$f, s) } }
+		}
+	};
+	(IntVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype_num! {
+			impl $crate::newtype::num_traits::CheckedAdd for $t {
+				fn checked_add(&self, v: &Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_add(self.$f, v.$f).map(|v| Self { $f: v }) }
+			}
+			impl $crate::newtype::num_traits::WrappingAdd for $t {
+				fn wrapping_add(&self, v: &Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_add(self.$f, v.$f) } }
+			}
+			impl $crate::newtype::num_traits::SaturatingAdd for $t {
+				fn saturating_add(&self, v: &Self) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_add(self.$f, v.$f) } }
+			}
+		}
+		impl ::core::ops::Not for $t {
+			type Output = Self;
+			fn not(self) -> Self { Self { $f: ::core::ops::Not::not(self.$f) } }
+		}
+		$crate::__newtype!(@bitops $t, $s, $f, BitAnd, bitand, BitOr, bitor, BitXor, bitxor);
+		$crate::__newtype!(@shifts $t, $f, i8, i16, i32, i64, u8, u16, u32, u64);
+		impl $crate::IntVec for $t {
+			type UnsignedScalar = <$i as $crate::IntVec>::UnsignedScalar;
+			type Unsigned = <$i as $crate::IntVec>::Unsigned;
+			type Signed = <$i as $crate::IntVec>::Signed;
+			fn checked_add(self, rhs: Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_add(self.$f, rhs.$f).map(|v| Self { $f: v }) }
+			fn checked_sub(self, rhs: Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_sub(self.$f, rhs.$f).map(|v| Self { $f: v }) }
+			fn checked_mul(self, rhs: Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_mul(self.$f, rhs.$f).map(|v| Self { $f: v }) }
+			fn checked_div(self, rhs: Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_div(self.$f, rhs.$f).map(|v| Self { $f: v }) }
+			fn checked_rem(self, rhs: Self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_rem(self.$f, rhs.$f).map(|v| Self { $f: v }) }
+			fn checked_neg(self) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_neg(self.$f).map(|v| Self { $f: v }) }
+			fn checked_pow(self, exp: u32) -> ::core::option::Option<Self> { <$i as $crate::IntVec>::checked_pow(self.$f, exp).map(|v| Self { $f: v }) }
+			fn wrapping_add(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_add(self.$f, rhs.$f) } }
+			fn wrapping_sub(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_sub(self.$f, rhs.$f) } }
+			fn wrapping_mul(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_mul(self.$f, rhs.$f) } }
+			fn wrapping_div(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_div(self.$f, rhs.$f) } }
+			fn wrapping_rem(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_rem(self.$f, rhs.$f) } }
+			fn wrapping_neg(self) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_neg(self.$f) } }
+			fn wrapping_pow(self, exp: u32) -> Self { Self { $f: <$i as $crate::IntVec>::wrapping_pow(self.$f, exp) } }
+			fn saturating_add(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_add(self.$f, rhs.$f) } }
+			fn saturating_sub(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_sub(self.$f, rhs.$f) } }
+			fn saturating_mul(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_mul(self.$f, rhs.$f) } }
+			fn saturating_div(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_div(self.$f, rhs.$f) } }
+			fn saturating_pow(self, exp: u32) -> Self { Self { $f: <$i as $crate::IntVec>::saturating_pow(self.$f, exp) } }
+			fn pow(self, exp: u32) -> Self { Self { $f: <$i as $crate::IntVec>::pow(self.$f, exp) } }
+			fn abs_diff(self, rhs: Self) -> Self::Unsigned { <$i as $crate::IntVec>::abs_diff(self.$f, rhs.$f) }
+			fn count_ones(self) -> Self::U32Vec { <$i as $crate::IntVec>::count_ones(self.$f) }
+			fn count_zeros(self) -> Self::U32Vec { <$i as $crate::IntVec>::count_zeros(self.$f) }
+			fn leading_zeros(self) -> Self::U32Vec { <$i as $crate::IntVec>::leading_zeros(self.$f) }
+			fn trailing_zeros(self) -> Self::U32Vec { <$i as $crate::IntVec>::trailing_zeros(self.$f) }
+			fn ilog2(self) -> Self::U32Vec { <$i as $crate::IntVec>::ilog2(self.$f) }
+			fn checked_ilog2(self) -> ::core::option::Option<Self::U32Vec> { <$i as $crate::IntVec>::checked_ilog2(self.$f) }
+			fn rotate_left(self, n: u32) -> Self { Self { $f: <$i as $crate::IntVec>::rotate_left(self.$f, n) } }
+			fn rotate_right(self, n: u32) -> Self { Self { $f: <$i as $crate::IntVec>::rotate_right(self.$f, n) } }
+			fn swap_bytes(self) -> Self { Self { $f: <$i as $crate::IntVec>::swap_bytes(self.$f) } }
+			fn reverse_bits(self) -> Self { Self { $f: <$i as $crate::IntVec>::reverse_bits(self.$f) } }
+			fn div_floor(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::div_floor(self.$f, rhs.$f) } }
+			fn div_ceil(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::div_ceil(self.$f, rhs.$f) } }
+			fn rem_floor(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::rem_floor(self.$f, rhs.$f) } }
+			fn round_up_to_multiple(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::round_up_to_multiple(self.$f, rhs.$f) } }
+			fn align_down(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::align_down(self.$f, rhs.$f) } }
+			fn gcd(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::gcd(self.$f, rhs.$f) } }
+			fn lcm(self, rhs: Self) -> Self { Self { $f: <$i as $crate::IntVec>::lcm(self.$f, rhs.$f) } }
+			fn manhattan_distance(self, rhs: Self) -> Self::UnsignedScalar { <$i as $crate::IntVec>::manhattan_distance(self.$f, rhs.$f) }
+			fn checked_manhattan_distance(self, rhs: Self) -> ::core::option::Option<Self::UnsignedScalar> { <$i as $crate::IntVec>::checked_manhattan_distance(self.$f, rhs.$f) }
+			fn chebyshev_distance(self, rhs: Self) -> Self::UnsignedScalar { <$i as $crate::IntVec>::chebyshev_distance(self.$f, rhs.$f) }
+		}
+	};
+	(IntVec2, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@shifts $t, $f, $crate::newtype::IVec2, $crate::newtype::UVec2);
+		impl $crate::IntVec2 for $t {}
+	};
+	(IntVec3, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@shifts $t, $f, $crate::newtype::IVec3, $crate::newtype::UVec3);
+		impl $crate::IntVec3 for $t {}
+	};
+	(IntVec4, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		$crate::__newtype!(@shifts $t, $f, $crate::newtype::IVec4, $crate::newtype::UVec4);
+		impl $crate::IntVec4 for $t {}
+	};
+	(SIntVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::SIntVec for $t {
+			fn unsigned_abs(self) -> Self::Unsigned { <$i as $crate::SIntVec>::unsigned_abs(self.$f) }
+			fn checked_add_unsigned(self, rhs: Self::Unsigned) -> ::core::option::Option<Self> { <$i as $crate::SIntVec>::checked_add_unsigned(self.$f, rhs).map(|v| Self { $f: v }) }
+			fn checked_sub_unsigned(self, rhs: Self::Unsigned) -> ::core::option::Option<Self> { <$i as $crate::SIntVec>::checked_sub_unsigned(self.$f, rhs).map(|v| Self { $f: v }) }
+			fn wrapping_add_unsigned(self, rhs: Self::Unsigned) -> Self { Self { $f: <$i as $crate::SIntVec>::wrapping_add_unsigned(self.$f, rhs) } }
+			fn wrapping_sub_unsigned(self, rhs: Self::Unsigned) -> Self { Self { $f: <$i as $crate::SIntVec>::wrapping_sub_unsigned(self.$f, rhs) } }
+			fn saturating_add_unsigned(self, rhs: Self::Unsigned) -> Self { Self { $f: <$i as $crate::SIntVec>::saturating_add_unsigned(self.$f, rhs) } }
+			fn saturating_sub_unsigned(self, rhs: Self::Unsigned) -> Self { Self { $f: <$i as $crate::SIntVec>::saturating_sub_unsigned(self.$f, rhs) } }
+		}
+	};
+	(UIntVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::UIntVec for $t {
+			fn checked_add_signed(self, rhs: Self::Signed) -> ::core::option::Option<Self> { <$i as $crate::UIntVec>::checked_add_signed(self.$f, rhs).map(|v| Self { $f: v }) }
+			fn wrapping_add_signed(self, rhs: Self::Signed) -> Self { Self { $f: <$i as $crate::UIntVec>::wrapping_add_signed(self.$f, rhs) } }
+			fn saturating_add_signed(self, rhs: Self::Signed) -> Self { Self { $f: <$i as $crate::UIntVec>::saturating_add_signed(self.$f, rhs) } }
+			fn div_euclid(self, rhs: Self) -> Self { Self { $f: <$i as $crate::UIntVec>::div_euclid(self.$f, rhs.$f) } }
+			fn rem_euclid(self, rhs: Self) -> Self { Self { $f: <$i as $crate::UIntVec>::rem_euclid(self.$f, rhs.$f) } }
+			fn is_power_of_two(self) -> Self::BVec { <$i as $crate::UIntVec>::is_power_of_two(self.$f) }
+			fn next_power_of_two(self) -> Self { Self { $f: <$i as $crate::UIntVec>::next_power_of_two(self.$f) } }
+		}
+	};
+	(PodVec, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::PodVec for $t {
+			fn slice_as_scalars(vecs: &[Self]) -> &[Self::Scalar] { $crate::newtype::bytemuck::cast_slice(vecs) }
+			fn slice_as_scalars_mut(vecs: &mut [Self]) -> &mut [Self::Scalar] { $crate::newtype::bytemuck::cast_slice_mut(vecs) }
+			fn slice_as_bytes(vecs: &[Self]) -> &[u8] { $crate::newtype::bytemuck::cast_slice(vecs) }
+		}
+	};
+	($marker:ident, $t:ident, $i:ty, $f:tt, $s:ty) => {
+		impl $crate::$marker for $t {}
+	};
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq};
+use glam::*;
+use glam_traits::*;
+
+fn vec<V: FloatVec>(v: V) {
+	let (eps, nudged) = (V::Scalar::EPSILON, v * (V::Scalar::ONE + V::Scalar::EPSILON));
+	assert_relative_eq!(v, nudged, max_relative = eps + eps);
+	assert_ulps_eq!(v, nudged);
+	assert_abs_diff_eq!(v, nudged, epsilon = (eps + eps) * v.abs().max_element());
+	assert_relative_ne!(v, v + V::ONE);
+	assert!(v.abs_diff_eq(nudged, V::Scalar::ONE));
+	assert!(v.relative_eq(nudged, eps, eps + eps));
+	assert!(v.ulps_eq(nudged, V::Scalar::ZERO, 4));
+	assert!(!v.ulps_eq(v + V::ONE, V::Scalar::ZERO, 4));
+}
+
+fn mat<M: GMat>(m: M) {
+	let eps = M::Scalar::EPSILON;
+	assert_relative_eq!(m, m.mul_scalar(M::Scalar::ONE + eps), max_relative = eps + eps);
+	assert_relative_ne!(m, m.mul_scalar(M::Scalar::ONE + M::Scalar::ONE));
+	assert!(m.abs_diff_eq(m, M::Scalar::ZERO));
+	assert_ulps_eq!(M::IDENTITY, M::IDENTITY);
+}
+
+fn quat<Q: GQuat>(q: Q) {
+	assert_relative_eq!(q, q);
+	assert_relative_ne!(q, Q::IDENTITY);
+	assert!(q.abs_diff_eq(q, Q::Scalar::ZERO));
+}
+
+fn affine<A: GAffine>(a: A) {
+	assert_ulps_eq!(a, a);
+	assert_relative_ne!(a, A::IDENTITY);
+	assert!(a.abs_diff_eq(a, A::Scalar::ZERO));
+}
+
+#[test]
+fn vectors() {
+	vec(Vec2::new(1.5, -2.0));
+	vec(Vec3::new(1.5, -2.0, 3.25));
+	vec(Vec3A::new(1.5, -2.0, 3.25));
+	vec(Vec4::new(1.5, -2.0, 3.25, 4.0));
+	vec(DVec2::new(1.5, -2.0));
+	vec(DVec3::new(1.5, -2.0, 3.25));
+	vec(DVec4::new(1.5, -2.0, 3.25, 4.0));
+}
+
+#[test]
+fn matrices() {
+	mat(Mat2::from_angle(0.5));
+	mat(Mat3::from_rotation_z(0.5));
+	mat(Mat3A::from_rotation_z(0.5));
+	mat(Mat4::from_rotation_z(0.5));
+	mat(DMat2::from_angle(0.5));
+	mat(DMat3::from_rotation_z(0.5));
+	mat(DMat4::from_rotation_z(0.5));
+}
+
+#[test]
+fn quaternions() {
+	quat(Quat::from_rotation_z(0.5));
+	quat(DQuat::from_rotation_z(0.5));
+}
+
+#[test]
+fn affines() {
+	affine(Affine2::from_angle(0.5));
+	affine(Affine3A::from_rotation_z(0.5));
+	affine(DAffine2::from_angle(0.5));
+	affine(DAffine3::from_rotation_z(0.5));
+}
@@ -0,0 +1,61 @@
+use glam::*;
+use glam_traits::BVec;
+
+fn masks<B: BVec>() {
+	let all = (1 << B::DIM) - 1;
+	assert_eq!(B::from_bitmask(0), B::FALSE);
+	assert_eq!(B::from_bitmask(all), B::TRUE);
+	assert_eq!(B::from_bitmask(!0), B::TRUE);
+	assert_eq!(B::from_bitmask(1 << B::DIM), B::FALSE);
+	for mask in 0..=all {
+		let b = B::from_bitmask(mask);
+		assert_eq!(b.bitmask(), mask);
+		assert_eq!(B::from_bitmask(b.bitmask()), b);
+		assert_eq!(b.count_true(), mask.count_ones());
+		assert_eq!(b.first_true(), (mask != 0).then(|| mask.trailing_zeros() as usize));
+		assert_eq!(b.first_false(), (mask != all).then(|| (!mask).trailing_zeros() as usize));
+		assert!(b.iter().enumerate().all(|(i, v)| v == (mask & (1 << i) != 0)));
+		for other in 0..=all {
+			let c = B::from_bitmask(other);
+			assert_eq!(b.and_not(c).bitmask(), mask & !other);
+			assert_eq!(b.cmpeq(c).bitmask(), !(mask ^ other) & all);
+			assert_eq!(b.cmpeq(c).all(), b == c);
+		}
+	}
+}
+
+fn firsts<B: BVec>() {
+	assert_eq!(B::FALSE.first_true(), None);
+	assert_eq!(B::FALSE.first_false(), Some(0));
+	assert_eq!(B::TRUE.first_true(), Some(0));
+	assert_eq!(B::TRUE.first_false(), None);
+	assert_eq!(B::FALSE.count_true(), 0);
+	assert_eq!(B::TRUE.count_true(), B::DIM as u32);
+	assert_eq!(B::from_bitmask(1 << (B::DIM - 1)).first_true(), Some(B::DIM - 1));
+	assert_eq!(B::from_bitmask(!(1 << (B::DIM - 1))).first_false(), Some(B::DIM - 1));
+}
+
+#[test]
+fn bitmask_round_trip() {
+	masks::<BVec2>();
+	masks::<BVec3>();
+	masks::<BVec3A>();
+	masks::<BVec4>();
+	masks::<BVec4A>();
+}
+
+#[test]
+fn first_true_and_false() {
+	firsts::<BVec2>();
+	firsts::<BVec3>();
+	firsts::<BVec3A>();
+	firsts::<BVec4>();
+	firsts::<BVec4A>();
+}
+
+#[test]
+fn and_not_and_cmpeq() {
+	assert_eq!(BVec3::new(true, true, false).and_not(BVec3::new(false, true, true)), BVec3::new(true, false, false));
+	assert_eq!(BVec4A::new(true, false, true, false).cmpeq(BVec4A::new(true, true, false, false)), BVec4A::new(true, false, false, true));
+	assert_eq!(BVec3A::TRUE.and_not(BVec3A::TRUE), BVec3A::FALSE);
+}
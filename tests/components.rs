@@ -0,0 +1,52 @@
+use glam::*;
+use glam_traits::GVec;
+
+fn indexed<V: GVec>() -> V where V::Scalar: From<u8> {
+	V::from_fn(|i| V::Scalar::from(i as u8 + 1))
+}
+
+#[test]
+fn from_fn() {
+	assert_eq!(indexed::<Vec2>(), Vec2::new(1.0, 2.0));
+	assert_eq!(indexed::<Vec3A>(), Vec3A::new(1.0, 2.0, 3.0));
+	assert_eq!(indexed::<U8Vec4>(), U8Vec4::new(1, 2, 3, 4));
+	assert_eq!(indexed::<I64Vec3>(), I64Vec3::new(1, 2, 3));
+	let mut calls = Vec::new();
+	let v = <DVec4 as GVec>::from_fn(|i| { calls.push(i); -(i as f64) });
+	assert_eq!(v, DVec4::new(0.0, -1.0, -2.0, -3.0));
+	assert_eq!(calls, [0, 1, 2, 3]);
+}
+
+#[test]
+fn iteration() {
+	let v = IVec3::new(4, -5, 6);
+	assert_eq!(GVec::iter(&v).copied().collect::<Vec<_>>(), [4, -5, 6]);
+	assert_eq!(GVec::into_iter(v).rev().collect::<Vec<_>>(), [6, -5, 4]);
+	assert_eq!(GVec::iter(&Vec3A::new(1.0, 2.0, 3.0)).count(), 3);
+	assert_eq!(GVec::into_iter(USizeVec2::new(7, 8)).sum::<usize>(), 15);
+}
+
+#[test]
+fn zip_map() {
+	assert_eq!(GVec::zip_map(IVec2::new(7, -7), IVec2::new(2, 2), |a, b| a.rem_euclid(b)), IVec2::new(1, 1));
+	assert_eq!(GVec::zip_map(U8Vec3::new(250, 0, 9), U8Vec3::splat(10), u8::saturating_add), U8Vec3::new(255, 10, 19));
+	assert_eq!(GVec::zip_map(Vec4::ONE, Vec4::new(1.0, 2.0, 3.0, 4.0), f32::max), Vec4::new(1.0, 2.0, 3.0, 4.0));
+	let mut order = Vec::new();
+	GVec::zip_map(DVec3::X, DVec3::Y, |a, b| { order.push((a, b)); a + b });
+	assert_eq!(order, [(1.0, 0.0), (0.0, 1.0), (0.0, 0.0)]);
+}
+
+#[test]
+fn map_to() {
+	assert_eq!(GVec::map_to(Vec3::new(1.5, -2.5, 3.0), |v| v as i32), IVec3::new(1, -2, 3));
+	assert_eq!(GVec::map_to(U8Vec2::new(3, 200), |v| v as f64 * 0.5), DVec2::new(1.5, 100.0));
+	assert_eq!(GVec::map_to(I16Vec4::new(-1, 0, 1, 2), |v| v.unsigned_abs() as u64), U64Vec4::new(1, 0, 1, 2));
+}
+
+#[test]
+fn fold() {
+	assert_eq!(GVec::fold(IVec4::new(1, 2, 3, 4), 0, |acc, v| acc * 10 + v), 1234);
+	assert_eq!(GVec::fold(Vec2::new(3.0, -4.0), 0.0, |acc: f32, v| acc + v * v), 25.0);
+	assert_eq!(GVec::fold(U8Vec3::MAX, 0u32, |acc, v| acc + v as u32), 765);
+	assert_eq!(GVec::fold(Vec3A::new(1.0, 2.0, 3.0), Vec::new(), |mut acc, v| { acc.push(v); acc }), [1.0, 2.0, 3.0]);
+}
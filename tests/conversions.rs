@@ -0,0 +1,51 @@
+use glam::*;
+use glam_traits::GVec;
+
+#[test]
+fn float_to_int() {
+	assert_eq!(Vec2::new(-1.5, 255.9).try_as_u8vec(), None);
+	assert_eq!(Vec2::new(-0.5, 255.9).try_as_u8vec(), Some(U8Vec2::new(0, 255)));
+	assert_eq!(Vec2::new(256.0, 0.0).try_as_u8vec(), None);
+	assert_eq!(Vec2::new(f32::NAN, 0.0).try_as_ivec(), None);
+	assert_eq!(Vec2::new(f32::INFINITY, 0.0).try_as_ivec(), None);
+	assert_eq!(Vec2::new(f32::NEG_INFINITY, 0.0).try_as_i64vec(), None);
+	assert_eq!(DVec2::new(i32::MAX as f64, i32::MIN as f64).try_as_ivec(), Some(IVec2::new(i32::MAX, i32::MIN)));
+	assert_eq!(DVec2::new(i32::MAX as f64 + 1.0, 0.0).try_as_ivec(), None);
+	assert_eq!(DVec2::new(i32::MIN as f64 - 1.0, 0.0).try_as_ivec(), None);
+	assert_eq!(Vec2::new(u64::MAX as f32, 0.0).try_as_u64vec(), None);
+	assert_eq!(Vec3::new(f32::NAN, f32::INFINITY, -1e10).saturating_as_ivec(), IVec3::new(0, i32::MAX, i32::MIN));
+	assert_eq!(DVec2::new(-1.0, 300.0).saturating_as_u8vec(), U8Vec2::new(0, 255));
+}
+
+#[test]
+fn int_to_float() {
+	assert_eq!(I64Vec2::new(i64::MAX, 0).try_as_vec(), None);
+	assert_eq!(I64Vec2::new(i64::MAX, 0).try_as_dvec(), None);
+	assert_eq!(I64Vec2::new(i64::MIN, 0).try_as_dvec(), Some(DVec2::new(i64::MIN as f64, 0.0)));
+	assert_eq!(U64Vec2::new(u64::MAX, 0).try_as_dvec(), None);
+	assert_eq!(IVec2::new((1 << 24) + 1, 0).try_as_vec(), None);
+	assert_eq!(IVec2::new(1 << 24, -(1 << 24)).try_as_vec(), Some(Vec2::new(16777216.0, -16777216.0)));
+	assert_eq!(IVec2::new((1 << 24) + 1, 0).try_as_dvec(), Some(DVec2::new(16777217.0, 0.0)));
+}
+
+#[test]
+fn float_to_float() {
+	assert_eq!(DVec2::new(f64::MAX, 0.0).try_as_vec(), None);
+	assert_eq!(DVec2::new(f64::INFINITY, 0.5).try_as_vec(), Some(Vec2::new(f32::INFINITY, 0.5)));
+	assert_eq!(DVec2::new(f64::NEG_INFINITY, 0.1).try_as_vec(), Some(Vec2::new(f32::NEG_INFINITY, 0.1)));
+	assert!(DVec2::new(f64::NAN, 0.0).try_as_vec().unwrap().x.is_nan());
+	assert_eq!(DVec2::new(16777217.0, f32::MAX as f64).try_as_vec(), Some(Vec2::new(16777216.0, f32::MAX)));
+	assert!(DVec2::new(f64::NAN, 0.0).saturating_as_vec().x.is_nan());
+	assert_eq!(DVec2::new(f64::MAX, f64::MIN).saturating_as_vec(), Vec2::new(f32::MAX, f32::MIN));
+}
+
+#[test]
+fn int_to_int() {
+	assert_eq!(IVec2::new(-1, 300).try_as_u8vec(), None);
+	assert_eq!(IVec2::new(0, 255).try_as_u8vec(), Some(U8Vec2::new(0, 255)));
+	assert_eq!(IVec2::new(-1, 300).saturating_as_u8vec(), U8Vec2::new(0, 255));
+	assert_eq!(I64Vec2::new(i64::MIN, i64::MAX).saturating_as_ivec(), IVec2::new(i32::MIN, i32::MAX));
+	assert_eq!(U64Vec2::new(u64::MAX, 0).saturating_as_i64vec(), I64Vec2::new(i64::MAX, 0));
+	assert_eq!(U64Vec2::new(i64::MAX as u64 + 1, 0).try_as_i64vec(), None);
+	assert_eq!(I8Vec2::new(i8::MIN, i8::MAX).saturating_as_u64vec(), U64Vec2::new(0, i8::MAX as u64));
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "derive")]
+
+use core::fmt::Debug;
+use glam::*;
+use glam_traits::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, GVec, GVec3, SignedVec, SignedVec3, FloatVec, FloatVec3, F32Vec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod, PodVec))]
+#[repr(transparent)]
+struct Position(Vec3);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, GVec, GVec2, SignedVec, SignedVec2, IntVec, IntVec2, SIntVec, SIntVec2, I32Vec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod, PodVec))]
+#[repr(transparent)]
+struct Offset(IVec2);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, GVec, GVec4, IntVec, IntVec4, UIntVec, UIntVec4, U8Vec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod, PodVec))]
+#[repr(transparent)]
+struct Color(U8Vec4);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, GVec, GVec2, SignedVec, SignedVec2, FloatVec, FloatVec2, F64Vec)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod, PodVec))]
+#[repr(transparent)]
+struct Velocity {
+	inner: DVec2,
+}
+
+/** Checks that the forwarded `GVec` methods agree with the wrapped vector `I`. */
+fn forwards<V: GVec + Into<I> + Debug, I: GVec<Scalar = V::Scalar> + Debug>(v: V, w: V) where V::Scalar: Debug {
+	let (vi, wi): (I, I) = (v.into(), w.into());
+	assert_eq!(V::DIM, I::DIM);
+	assert_eq!(v.dot(w), vi.dot(wi));
+	assert_eq!(Into::<I>::into(v.min(w)), vi.min(wi));
+	assert_eq!(Into::<I>::into(v.max(w)), vi.max(wi));
+	assert_eq!(Into::<I>::into(v + w), vi + wi);
+	assert_eq!(Into::<I>::into(v * w), vi * wi);
+	assert_eq!(v.iter().copied().collect::<Vec<_>>(), vi.iter().copied().collect::<Vec<_>>());
+	assert_eq!(V::from_fn(|i| v[i]), v);
+	assert_eq!(v.fold(V::Scalar::ZERO, |a, b| a + b), vi.element_sum());
+	assert_eq!(V::splat(V::Scalar::ONE), V::ONE);
+	assert!(v.cmpeq(v).all());
+	assert_ne!(v, w);
+}
+
+#[test]
+fn float() {
+	let v = Position(Vec3::new(1.0, -2.0, 3.0));
+	forwards::<Position, Vec3>(v, Position(Vec3::new(0.5, 4.0, -1.0)));
+	let n = v.normalize();
+	assert!((n.length() - 1.0).abs() < 1e-6);
+	assert!(n.dot(n.any_orthonormal_vector()).abs() < 1e-6);
+	assert!((Quat::from_rotation_z(core::f32::consts::FRAC_PI_2) * Position::X).abs_diff_eq(Position::Y, 1e-6));
+	assert_eq!(v.lerp(v * 3.0, 0.5), v * 2.0);
+	assert_eq!(v.xzy(), Position(Vec3::new(1.0, 3.0, -2.0)));
+	assert_eq!(v.extend(4.0), Vec4::new(1.0, -2.0, 3.0, 4.0));
+	assert_eq!(Position::from(Vec3::X).0, Vec3::X);
+	assert!(v.is_finite() && !v.is_nan());
+}
+
+#[test]
+fn signed_int() {
+	let v = Offset(IVec2::new(-3, 8));
+	forwards::<Offset, IVec2>(v, Offset(IVec2::new(5, -1)));
+	assert_eq!(v.perp_dot(Offset(IVec2::new(5, -1))), -37);
+	assert_eq!(v.yx(), Offset(IVec2::new(8, -3)));
+	assert_eq!(v.unsigned_abs(), UVec2::new(3, 8));
+	assert_eq!(v.checked_neg(), Some(Offset(IVec2::new(3, -8))));
+	assert_eq!(Offset::MIN.checked_neg(), None);
+	assert_eq!(v.signum() * v.abs(), v);
+	assert_eq!(v.rotate_left(3).rotate_right(3), v);
+	assert_eq!(v.div_floor(Offset::splat(2)), Offset(IVec2::new(-2, 4)));
+}
+
+#[test]
+fn unsigned_int() {
+	let v = Color(U8Vec4::new(10, 9, 1, 7));
+	forwards::<Color, U8Vec4>(v, Color(U8Vec4::new(2, 11, 1, 0)));
+	assert_eq!(v.checked_add_signed(I8Vec4::NEG_ONE), Some(Color(U8Vec4::new(9, 8, 0, 6))));
+	assert_eq!(v.saturating_add_signed(I8Vec4::MIN), Color::ZERO);
+	assert_eq!(Color(U8Vec4::MAX).checked_add(Color::ONE), None);
+	assert_eq!(v.count_ones() + v.count_zeros(), UVec4::splat(8));
+	assert_eq!(Color::W.truncate(), U8Vec3::ZERO);
+}
+
+#[test]
+fn named_field() {
+	let v = Velocity { inner: DVec2::new(3.0, -4.0) };
+	forwards::<Velocity, DVec2>(v, Velocity { inner: DVec2::new(-1.0, 0.5) });
+	assert_eq!(v.length(), 5.0);
+	assert_eq!(v.perp_dot(v), 0.0);
+	assert_eq!(v.x(), 3.0);
+	assert_eq!(v.with_y(1.0), Velocity { inner: DVec2::new(3.0, 1.0) });
+	assert_eq!(v.extend(2.0), DVec3::new(3.0, -4.0, 2.0));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+	let v = Offset(IVec2::new(-3, 8));
+	let json = serde_json::to_string(&v).unwrap();
+	assert_eq!(json, serde_json::to_string(&v.0).unwrap());
+	assert_eq!(serde_json::from_str::<Offset>(&json).unwrap(), v);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn pod() {
+	assert_eq!(Position::slice_as_scalars(&[Position(Vec3::X), Position(Vec3::Y)]), [1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+	assert_eq!(Color::slice_as_scalars(&[Color(U8Vec4::new(1, 2, 3, 4))]), [1, 2, 3, 4]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn podvec_rejects_vec3a() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,47 @@
+use glam::*;
+use glam_traits::FloatVec;
+
+fn steps<V: FloatVec>(below: V, at: V, above: V, edge0: V, edge1: V, half: V) {
+	assert_eq!(FloatVec::step(below, at), V::ZERO);
+	assert_eq!(FloatVec::step(at, at), V::ONE);
+	assert_eq!(FloatVec::step(above, at), V::ONE);
+	assert_eq!(FloatVec::smoothstep(below, edge0, edge1), V::ZERO);
+	assert_eq!(FloatVec::smoothstep(edge0, edge0, edge1), V::ZERO);
+	assert_eq!(FloatVec::smoothstep(half, edge0, edge1), V::ONE / (V::ONE + V::ONE));
+	assert_eq!(FloatVec::smoothstep(edge1, edge0, edge1), V::ONE);
+	assert_eq!(FloatVec::smoothstep(above, edge0, edge1), V::ONE);
+	assert_eq!(FloatVec::smoothstep(below, at, at), V::ZERO);
+	assert_eq!(FloatVec::smoothstep(at, at, at), V::ZERO);
+	assert_eq!(FloatVec::smoothstep(above, at, at), V::ONE);
+	assert_eq!(FloatVec::saturate(V::NEG_ONE), V::ZERO);
+	assert_eq!(FloatVec::saturate(V::ONE / (V::ONE + V::ONE)), V::ONE / (V::ONE + V::ONE));
+	assert_eq!(FloatVec::saturate(V::ONE + V::ONE), V::ONE);
+	assert_eq!(FloatVec::saturate(V::INFINITY), V::ONE);
+	assert_eq!(FloatVec::saturate(V::NEG_INFINITY), V::ZERO);
+}
+
+#[test]
+fn step_smoothstep_saturate() {
+	steps(Vec2::new(-1.0, 0.5), Vec2::new(0.0, 1.0), Vec2::new(2.0, 2.5), Vec2::new(0.0, 1.0), Vec2::new(1.0, 2.0), Vec2::new(0.5, 1.5));
+	steps(Vec3A::splat(-0.5), Vec3A::ZERO, Vec3A::splat(0.5), Vec3A::splat(-0.25), Vec3A::splat(0.25), Vec3A::ZERO);
+	steps(Vec4::splat(0.0), Vec4::ONE, Vec4::splat(3.0), Vec4::splat(0.5), Vec4::splat(2.5), Vec4::splat(1.5));
+	steps(DVec3::new(-2.0, 0.0, 9.0), DVec3::new(-1.0, 1.0, 10.0), DVec3::new(2.0, 3.0, 11.0), DVec3::new(-1.5, 0.5, 9.5), DVec3::new(1.0, 2.0, 10.5), DVec3::new(-0.25, 1.25, 10.0));
+}
+
+#[test]
+fn smoothstep_is_cubic() {
+	assert_eq!(FloatVec::smoothstep(Vec2::new(0.25, 0.75), Vec2::ZERO, Vec2::ONE), Vec2::new(0.15625, 0.84375));
+	assert_eq!(FloatVec::smoothstep(DVec2::new(0.75, 0.25), DVec2::ONE, DVec2::ZERO), DVec2::new(0.15625, 0.84375));
+}
+
+#[test]
+fn sin_cos() {
+	let angles = Vec4::new(0.0, core::f32::consts::FRAC_PI_2, core::f32::consts::PI, -1.0);
+	let (sin, cos) = FloatVec::sin_cos(angles);
+	assert!(sin.abs_diff_eq(Vec4::from_array(angles.to_array().map(f32::sin)), 1e-6));
+	assert!(cos.abs_diff_eq(Vec4::from_array(angles.to_array().map(f32::cos)), 1e-6));
+	assert_eq!((sin.x, cos.x), (0.0, 1.0));
+	let (sin, cos) = FloatVec::sin_cos(DVec3::new(0.5, -2.0, 4.0));
+	assert!((sin * sin + cos * cos).abs_diff_eq(DVec3::ONE, 1e-15));
+	assert!((sin.x - 0.5f64.sin()).abs() < 1e-15 && (cos.z - 4.0f64.cos()).abs() < 1e-15);
+}
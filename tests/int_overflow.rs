@@ -0,0 +1,147 @@
+use glam::*;
+use glam_traits::{IntVec, SIntVec};
+
+fn checked<V: IntVec>(min: V, max: V, neg_one: V, two: V) {
+	assert_eq!(IntVec::checked_rem(min, neg_one), None);
+	assert_eq!(IntVec::checked_rem(max, V::ZERO), None);
+	assert_eq!(IntVec::checked_rem(max, two), Some(V::ONE));
+	assert_eq!(IntVec::checked_neg(min), None);
+	assert_eq!(IntVec::checked_neg(max), Some(min + V::ONE));
+	assert_eq!(IntVec::checked_pow(two, 1000), None);
+	assert_eq!(IntVec::checked_pow(neg_one, 1001), Some(neg_one));
+	assert_eq!(IntVec::checked_pow(max, 1), Some(max));
+	assert_eq!(IntVec::checked_pow(max, 0), Some(V::ONE));
+}
+
+fn wrapping<V: IntVec>(min: V, max: V, neg_one: V) {
+	assert_eq!(IntVec::wrapping_add(V::MAX, V::ONE), min);
+	assert_eq!(IntVec::wrapping_sub(min, V::ONE), max);
+	assert_eq!(IntVec::wrapping_mul(V::MAX, V::MAX), V::ONE);
+	assert_eq!(IntVec::wrapping_div(min, neg_one), min);
+	assert_eq!(IntVec::wrapping_rem(min, neg_one), V::ZERO);
+	assert_eq!(IntVec::wrapping_neg(min), min);
+	assert_eq!(IntVec::wrapping_neg(max), min + V::ONE);
+	assert_eq!(IntVec::wrapping_pow(max, 2), V::ONE);
+	assert_eq!(IntVec::wrapping_pow(neg_one, 1001), neg_one);
+	assert_eq!(IntVec::saturating_pow(max, 2), max);
+	assert_eq!(IntVec::saturating_pow(min, 3), min);
+	assert_eq!(IntVec::saturating_pow(min, 2), max);
+}
+
+#[test]
+fn checked_at_bounds() {
+	checked(I8Vec2::MIN, I8Vec2::MAX, I8Vec2::NEG_ONE, I8Vec2::splat(2));
+	checked(I16Vec3::MIN, I16Vec3::MAX, I16Vec3::NEG_ONE, I16Vec3::splat(2));
+	checked(IVec4::MIN, IVec4::MAX, IVec4::NEG_ONE, IVec4::splat(2));
+	checked(I64Vec2::MIN, I64Vec2::MAX, I64Vec2::NEG_ONE, I64Vec2::splat(2));
+	assert_eq!(IntVec::checked_neg(IVec2::splat(i32::MIN)), None);
+	assert_eq!(IntVec::checked_neg(IVec2::new(i32::MIN, 1)), None);
+	assert_eq!(IntVec::checked_rem(IVec2::new(i32::MIN, 7), IVec2::new(-1, 2)), None);
+	assert_eq!(IntVec::checked_rem(IVec2::new(i32::MIN, 7), IVec2::new(-2, 2)), Some(IVec2::new(0, 1)));
+	assert_eq!(IntVec::checked_pow(IVec2::new(-2, 2), 31), None);
+	assert_eq!(IntVec::checked_pow(IVec2::new(-2, 1), 31), Some(IVec2::new(i32::MIN, 1)));
+	assert_eq!(IntVec::checked_neg(UVec2::new(0, 0)), Some(UVec2::ZERO));
+	assert_eq!(IntVec::checked_neg(UVec2::new(0, 1)), None);
+	assert_eq!(IntVec::checked_pow(U8Vec2::new(2, 16), 2), None);
+	assert_eq!(IntVec::checked_pow(U8Vec2::new(2, 15), 2), Some(U8Vec2::new(4, 225)));
+	assert_eq!(IntVec::checked_rem(U64Vec2::MAX, U64Vec2::new(1, 0)), None);
+}
+
+#[test]
+fn wrapping_at_bounds() {
+	wrapping(I8Vec4::MIN, I8Vec4::MAX, I8Vec4::NEG_ONE);
+	wrapping(I16Vec2::MIN, I16Vec2::MAX, I16Vec2::NEG_ONE);
+	wrapping(IVec3::MIN, IVec3::MAX, IVec3::NEG_ONE);
+	wrapping(I64Vec4::MIN, I64Vec4::MAX, I64Vec4::NEG_ONE);
+	assert_eq!(IntVec::wrapping_neg(U16Vec2::new(0, 1)), U16Vec2::new(0, u16::MAX));
+	assert_eq!(IntVec::wrapping_add(UVec2::MAX, UVec2::ONE), UVec2::ZERO);
+	assert_eq!(IntVec::wrapping_sub(U64Vec2::ZERO, U64Vec2::ONE), U64Vec2::MAX);
+	assert_eq!(IntVec::wrapping_rem(U8Vec2::MAX, U8Vec2::new(16, 255)), U8Vec2::new(15, 0));
+	assert_eq!(IntVec::wrapping_pow(U8Vec2::new(2, 16), 8), U8Vec2::new(0, 0));
+	assert_eq!(IntVec::saturating_pow(U8Vec2::new(2, 15), 8), U8Vec2::new(255, 255));
+	assert_eq!(IntVec::saturating_pow(U8Vec2::new(2, 15), 2), U8Vec2::new(4, 225));
+}
+
+#[test]
+fn abs_diff_at_bounds() {
+	assert_eq!(IntVec::abs_diff(I8Vec2::MIN, I8Vec2::MAX), U8Vec2::MAX);
+	assert_eq!(IntVec::abs_diff(I8Vec2::MAX, I8Vec2::MIN), U8Vec2::MAX);
+	assert_eq!(IntVec::abs_diff(IVec3::new(i32::MIN, -1, 0), IVec3::new(0, i32::MAX, i32::MIN)), UVec3::new(1 << 31, 1 << 31, 1 << 31));
+	assert_eq!(IntVec::abs_diff(I64Vec2::MIN, I64Vec2::MAX), U64Vec2::MAX);
+	assert_eq!(IntVec::abs_diff(U64Vec2::new(0, u64::MAX), U64Vec2::new(u64::MAX, 0)), U64Vec2::MAX);
+	assert_eq!(IntVec::abs_diff(USizeVec2::ZERO, USizeVec2::MAX), USizeVec2::MAX);
+	assert_eq!(SIntVec::unsigned_abs(IVec2::new(i32::MIN, i32::MAX)), UVec2::new(1 << 31, i32::MAX as u32));
+}
+
+#[test]
+fn ilog2_at_bounds() {
+	assert_eq!(IntVec::checked_ilog2(IVec3::new(1, 2, i32::MAX)), Some(UVec3::new(0, 1, 30)));
+	assert_eq!(IntVec::checked_ilog2(IVec2::new(1, 0)), None);
+	assert_eq!(IntVec::checked_ilog2(IVec2::new(i32::MIN, 8)), None);
+	assert_eq!(IntVec::checked_ilog2(U64Vec2::new(u64::MAX, 1)), Some(UVec2::new(63, 0)));
+	assert_eq!(IntVec::checked_ilog2(U8Vec2::new(255, 0)), None);
+	assert_eq!(IntVec::ilog2(USizeVec2::new(usize::MAX, 1)), UVec2::new(usize::BITS - 1, 0));
+}
+
+#[test]
+#[should_panic]
+fn ilog2_of_zero() {
+	IntVec::ilog2(IVec2::new(4, 0));
+}
+
+#[test]
+fn gcd_lcm_at_bounds() {
+	assert_eq!(IntVec::gcd(IVec4::new(i32::MIN, i32::MIN, i32::MIN, i32::MAX), IVec4::new(0, -1, 6, i32::MIN)), IVec4::new(i32::MIN, 1, 2, 1));
+	assert_eq!(IntVec::gcd(I8Vec2::new(-12, 0), I8Vec2::new(18, -7)), I8Vec2::new(6, 7));
+	assert_eq!(IntVec::lcm(I8Vec2::new(-4, 0), I8Vec2::new(6, i8::MIN)), I8Vec2::new(12, 0));
+	assert_eq!(IntVec::lcm(IVec2::new(i32::MAX, i32::MIN), IVec2::new(2, -1)), IVec2::new(i32::MAX.wrapping_mul(2).wrapping_abs(), i32::MIN));
+	assert_eq!(IntVec::gcd(U64Vec2::new(u64::MAX, 0), U64Vec2::new(0, 0)), U64Vec2::new(u64::MAX, 0));
+	assert_eq!(IntVec::lcm(U8Vec2::new(16, 255), U8Vec2::new(17, 255)), U8Vec2::new(16, 255));
+}
+
+fn rounding<V: IntVec>(lhs: V, rhs: V, floor: V, ceil: V, rem: V, down: V, up: V) {
+	assert_eq!(IntVec::div_floor(lhs, rhs), floor);
+	assert_eq!(IntVec::div_ceil(lhs, rhs), ceil);
+	assert_eq!(IntVec::rem_floor(lhs, rhs), rem);
+	assert_eq!(IntVec::wrapping_add(IntVec::wrapping_mul(floor, rhs), rem), lhs);
+	assert_eq!(IntVec::align_down(lhs, rhs), down);
+	assert_eq!(IntVec::round_up_to_multiple(lhs, rhs), up);
+	assert_eq!(IntVec::div_floor(V::MAX, V::ONE), V::MAX);
+	assert_eq!(IntVec::div_ceil(V::MAX, V::MAX), V::ONE);
+	assert_eq!(IntVec::align_down(V::MAX, V::ONE), V::MAX);
+	assert_eq!(IntVec::round_up_to_multiple(V::MAX, V::MAX), V::MAX);
+	assert_eq!(IntVec::round_up_to_multiple(V::ZERO, rhs), V::ZERO);
+}
+
+#[test]
+fn rounding_division() {
+	let (lhs, pos, neg) = (IVec4::new(7, -7, 6, -6), IVec4::splat(2), IVec4::splat(-2));
+	rounding(lhs, pos, IVec4::new(3, -4, 3, -3), IVec4::new(4, -3, 3, -3), IVec4::new(1, 1, 0, 0), IVec4::new(6, -8, 6, -6), IVec4::new(8, -6, 6, -6));
+	rounding(lhs, neg, IVec4::new(-4, 3, -3, 3), IVec4::new(-3, 4, -3, 3), IVec4::new(-1, -1, 0, 0), IVec4::new(6, -8, 6, -6), IVec4::new(8, -6, 6, -6));
+	rounding(I8Vec2::new(-128, 127), I8Vec2::splat(3), I8Vec2::new(-43, 42), I8Vec2::new(-42, 43), I8Vec2::new(1, 1), I8Vec2::new(127, 126), I8Vec2::new(-126, -127));
+	rounding(U8Vec3::new(7, 0, 255), U8Vec3::new(2, 5, 16), U8Vec3::new(3, 0, 15), U8Vec3::new(4, 0, 16), U8Vec3::new(1, 0, 15), U8Vec3::new(6, 0, 240), U8Vec3::new(8, 0, 0));
+	rounding(U64Vec2::new(u64::MAX, 10), U64Vec2::new(u64::MAX - 1, 3), U64Vec2::new(1, 3), U64Vec2::new(2, 4), U64Vec2::new(1, 1), U64Vec2::new(u64::MAX - 1, 9), U64Vec2::new(u64::MAX - 3, 12));
+	assert_eq!(IntVec::align_down(I8Vec2::new(-128, 127), I8Vec2::new(-1, i8::MIN)), I8Vec2::new(-128, 0));
+	assert_eq!(IntVec::round_up_to_multiple(I8Vec2::new(-128, 1), I8Vec2::new(-1, i8::MIN)), I8Vec2::new(-128, -128));
+	assert_eq!(IntVec::div_floor(I64Vec2::new(i64::MIN, i64::MAX), I64Vec2::new(i64::MAX, i64::MIN)), I64Vec2::new(-2, -1));
+	assert_eq!(IntVec::rem_floor(I64Vec2::new(i64::MIN, i64::MAX), I64Vec2::new(i64::MAX, i64::MIN)), I64Vec2::new(i64::MAX - 1, -1));
+}
+
+#[test]
+#[should_panic]
+fn div_floor_by_zero() {
+	IntVec::div_floor(IVec2::new(4, 1), IVec2::new(2, 0));
+}
+
+#[test]
+#[should_panic]
+fn align_down_by_zero() {
+	IntVec::align_down(UVec2::new(4, 1), UVec2::new(2, 0));
+}
+
+#[test]
+#[cfg_attr(not(debug_assertions), ignore = "pow wraps without debug assertions")]
+#[should_panic]
+fn pow_overflow() {
+	IntVec::pow(IVec2::new(2, 1), 31);
+}
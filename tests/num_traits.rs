@@ -0,0 +1,56 @@
+#![cfg(feature = "num-traits")]
+
+use glam::*;
+use glam_traits::*;
+use num_traits::{Bounded, Float, PrimInt, Signed};
+
+fn float<V: NumFloatVec>(v: V) {
+	let (x, y) = (v[0], v[1]);
+	assert_eq!(Float::hypot(x, y), FloatScalar::sqrt(x * x + y * y));
+	assert_eq!(Float::max(x, y), y);
+	assert!(Float::is_sign_negative(Float::min(x, y)));
+	assert_eq!(<V::Scalar as Float>::infinity(), V::INFINITY[0]);
+	assert_eq!(<V::Scalar as Float>::max_value(), V::Scalar::MAX);
+}
+
+fn int<V: NumIntVec>(v: V) {
+	let x = v[0];
+	assert_eq!(PrimInt::count_ones(x), IntVec::count_ones(v)[0]);
+	assert_eq!(PrimInt::rotate_left(x, 3), IntVec::rotate_left(v, 3)[0]);
+	assert_eq!(PrimInt::pow(x, 2), IntVec::pow(v, 2)[0]);
+	assert_eq!(<V::Scalar as Bounded>::min_value(), V::Scalar::MIN);
+}
+
+fn sint<V: NumSIntVec>(v: V) {
+	assert_eq!(Signed::abs(&v[0]), SignedVec::abs(v)[0]);
+	assert_eq!(Signed::signum(&v[0]), SignedVec::signum(v)[0]);
+	assert!(Signed::is_negative(&v[0]));
+	int(v);
+}
+
+fn uint<V: NumUIntVec>(v: V) {
+	assert_eq!(PrimInt::leading_zeros(v[0]), IntVec::leading_zeros(v)[0]);
+	assert_eq!(num_traits::Zero::is_zero(&v[0]), v[0] == V::Scalar::ZERO);
+	int(v);
+}
+
+#[test]
+fn floats() {
+	float(Vec2::new(-3.0, 4.0));
+	float(Vec3A::new(-3.0, 4.0, 12.0));
+	float(DVec4::new(-3.0, 4.0, 12.0, 84.0));
+}
+
+#[test]
+fn signed_ints() {
+	sint(I8Vec2::new(-3, 4));
+	sint(IVec3::new(-3, 4, 12));
+	sint(I64Vec4::new(-3, 4, 12, 84));
+}
+
+#[test]
+fn unsigned_ints() {
+	uint(U8Vec2::new(3, 4));
+	uint(UVec3::new(0, 4, 12));
+	uint(USizeVec4::new(3, 4, 12, 84));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "rand")]
+
+use glam::*;
+use glam_traits::*;
+use rand::{rngs::SmallRng, SeedableRng};
+
+const SAMPLES: usize = 1000;
+
+fn circle<V: RandomFloatVec2>(rng: &mut SmallRng, tolerance: V::Scalar) {
+	for _ in 0..SAMPLES {
+		assert!((V::random_on_unit_circle(rng).length() - V::Scalar::ONE).abs() <= tolerance);
+		assert!(V::random_in_unit_disk(rng).length() <= V::Scalar::ONE + tolerance);
+	}
+}
+
+fn sphere<V: RandomFloatVec3>(rng: &mut SmallRng, tolerance: V::Scalar) {
+	let normals = [V::X, V::NEG_Y, V::new(V::Scalar::ONE, V::Scalar::ONE, -V::Scalar::ONE).normalize()];
+	for _ in 0..SAMPLES {
+		assert!((V::random_on_unit_sphere(rng).length() - V::Scalar::ONE).abs() <= tolerance);
+		assert!(V::random_in_unit_ball(rng).length() <= V::Scalar::ONE + tolerance);
+		for normal in normals {
+			let v = V::random_on_hemisphere(rng, normal);
+			assert!((v.length() - V::Scalar::ONE).abs() <= tolerance);
+			assert!(v.dot(normal) >= V::Scalar::ZERO);
+		}
+	}
+}
+
+#[test]
+fn unit_circle_and_disk() {
+	let mut rng = SmallRng::seed_from_u64(1);
+	circle::<Vec2>(&mut rng, 1e-6);
+	circle::<DVec2>(&mut rng, 1e-12);
+}
+
+#[test]
+fn unit_sphere_ball_and_hemisphere() {
+	let mut rng = SmallRng::seed_from_u64(2);
+	sphere::<Vec3>(&mut rng, 1e-6);
+	sphere::<Vec3A>(&mut rng, 1e-6);
+	sphere::<DVec3>(&mut rng, 1e-12);
+}
+
+#[test]
+fn random_range() {
+	let mut rng = SmallRng::seed_from_u64(3);
+	for _ in 0..SAMPLES {
+		let v = IVec3::random_range(&mut rng, IVec3::new(-2, 0, 5), IVec3::new(2, 0, 7));
+		assert!(v.cmpge(IVec3::new(-2, 0, 5)).all() && v.cmple(IVec3::new(2, 0, 7)).all());
+		let v = Vec2::random_range(&mut rng, Vec2::splat(-1.0), Vec2::new(1.0, -1.0));
+		assert!(v.x.abs() <= 1.0 && v.y == -1.0);
+	}
+}
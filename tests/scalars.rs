@@ -0,0 +1,23 @@
+use glam_traits::{FloatScalar, SignedScalar};
+
+fn signed<S: FloatScalar + core::fmt::Debug>(x: S) {
+	assert_eq!(SignedScalar::abs(-x), x);
+	assert_eq!(SignedScalar::abs(x), x);
+	assert_eq!(SignedScalar::signum(x), S::ONE);
+	assert_eq!(SignedScalar::signum(-x), S::NEG_ONE);
+	assert_eq!(SignedScalar::signum(S::ZERO), S::ONE);
+	assert_eq!(SignedScalar::signum(-S::ZERO), S::NEG_ONE);
+	assert_eq!(SignedScalar::signum(S::NEG_INFINITY), S::NEG_ONE);
+	assert!(SignedScalar::signum(S::NAN).is_nan());
+	assert_eq!(SignedScalar::signum(SignedScalar::abs(-S::ZERO)), S::ONE);
+	assert_eq!(SignedScalar::rem_euclid(-x, -(x + x)), x);
+	assert_eq!(SignedScalar::div_euclid(-x, -(x + x)), S::ONE);
+}
+
+#[test]
+fn float_abs_and_signum() {
+	signed(1.5f32);
+	signed(1.5f64);
+	assert_eq!(SignedScalar::abs(f32::NEG_INFINITY), f32::INFINITY);
+	assert_eq!(SignedScalar::signum(-7i32), -1);
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+use core::fmt::Debug;
+use glam::*;
+use glam_traits::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+const ELEMENTS: [f32; 16] = [1.5, -2.0, 3.25, 4.0, 5.0, -6.5, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+const D_ELEMENTS: [f64; 16] = [1.5, -2.0, 3.25, 4.0, 5.0, -6.5, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0];
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+	let json = serde_json::to_string(&value).unwrap();
+	assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "{json}");
+}
+
+fn vec<V: GVec>() {
+	round_trip(V::MIN);
+	round_trip(V::ONE);
+	round_trip(V::MAX);
+}
+
+fn mat<M: GMat>(m: M) {
+	round_trip(m);
+	round_trip(M::IDENTITY);
+}
+
+fn quat<Q: GQuat>(q: Q) {
+	round_trip(q);
+	round_trip(Q::IDENTITY);
+}
+
+fn affine<A: GAffine>(a: A) {
+	round_trip(a);
+	round_trip(A::IDENTITY);
+}
+
+fn bvec<B: BVec + Serialize + DeserializeOwned>() {
+	for mask in 0..1 << B::DIM {
+		round_trip(B::from_bitmask(mask));
+	}
+}
+
+#[test]
+fn vectors() {
+	vec::<I8Vec2>();
+	vec::<I8Vec3>();
+	vec::<I8Vec4>();
+	vec::<U8Vec2>();
+	vec::<U8Vec3>();
+	vec::<U8Vec4>();
+	vec::<I16Vec2>();
+	vec::<I16Vec3>();
+	vec::<I16Vec4>();
+	vec::<U16Vec2>();
+	vec::<U16Vec3>();
+	vec::<U16Vec4>();
+	vec::<IVec2>();
+	vec::<IVec3>();
+	vec::<IVec4>();
+	vec::<UVec2>();
+	vec::<UVec3>();
+	vec::<UVec4>();
+	vec::<I64Vec2>();
+	vec::<I64Vec3>();
+	vec::<I64Vec4>();
+	vec::<U64Vec2>();
+	vec::<U64Vec3>();
+	vec::<U64Vec4>();
+	vec::<USizeVec2>();
+	vec::<USizeVec3>();
+	vec::<USizeVec4>();
+	vec::<Vec2>();
+	vec::<Vec3>();
+	vec::<Vec3A>();
+	vec::<Vec4>();
+	vec::<DVec2>();
+	vec::<DVec3>();
+	vec::<DVec4>();
+}
+
+#[test]
+fn matrices() {
+	mat(Mat2::from_cols_slice(&ELEMENTS));
+	mat(Mat3::from_cols_slice(&ELEMENTS));
+	mat(Mat3A::from_cols_slice(&ELEMENTS));
+	mat(Mat4::from_cols_slice(&ELEMENTS));
+	mat(DMat2::from_cols_slice(&D_ELEMENTS));
+	mat(DMat3::from_cols_slice(&D_ELEMENTS));
+	mat(DMat4::from_cols_slice(&D_ELEMENTS));
+}
+
+#[test]
+fn quaternions() {
+	quat(Quat::from_xyzw(0.5, -0.5, 0.5, 0.5));
+	quat(DQuat::from_xyzw(0.5, -0.5, 0.5, 0.5));
+}
+
+#[test]
+fn affines() {
+	affine(Affine2::from_cols_slice(&ELEMENTS));
+	affine(Affine3A::from_cols_slice(&ELEMENTS));
+	affine(DAffine2::from_cols_slice(&D_ELEMENTS));
+	affine(DAffine3::from_cols_slice(&D_ELEMENTS));
+}
+
+#[test]
+fn bool_vectors() {
+	bvec::<BVec2>();
+	bvec::<BVec3>();
+	bvec::<BVec4>();
+	#[cfg(not(feature = "scalar-math"))]
+	bvec::<BVec3A>();
+}
+
+#[test]
+#[cfg(not(feature = "scalar-math"))]
+fn bvec4a_writes_z_in_place_of_w() {
+	let json = serde_json::to_string(&BVec4A::new(false, false, true, false)).unwrap();
+	assert_eq!(json, "[false,false,true,true]");
+	assert_eq!(serde_json::from_str::<BVec4A>(&json).unwrap(), BVec4A::new(false, false, true, true));
+	assert_eq!(serde_json::to_string(&BVec4A::new(false, false, false, true)).unwrap(), "[false,false,false,false]");
+}
@@ -0,0 +1,50 @@
+use glam::*;
+use glam_traits::{GVec, SliceLenError};
+
+fn round_trip<V: GVec>(elements: &[V::Scalar]) {
+	let v = V::try_from_slice(elements).unwrap();
+	let mut out = elements.to_vec();
+	out.iter_mut().for_each(|e| *e = V::Scalar::default());
+	v.try_write_to_slice(&mut out).unwrap();
+	assert_eq!(&out[..V::DIM], &elements[..V::DIM]);
+}
+
+#[test]
+fn single_vector() {
+	round_trip::<Vec2>(&[1.0, 2.0]);
+	round_trip::<Vec3A>(&[1.0, 2.0, 3.0, 4.0]);
+	round_trip::<I8Vec4>(&[i8::MIN, -1, 0, i8::MAX]);
+	round_trip::<USizeVec3>(&[0, 1, usize::MAX]);
+	assert_eq!(<IVec3 as GVec>::try_from_slice(&[1, 2, 3, 4]), Ok(IVec3::new(1, 2, 3)));
+	assert_eq!(<IVec3 as GVec>::try_from_slice(&[1, 2]), Err(SliceLenError { expected: 3, actual: 2 }));
+	assert_eq!(<DVec4 as GVec>::try_from_slice(&[]), Err(SliceLenError { expected: 4, actual: 0 }));
+	let mut short = [0u16; 1];
+	assert_eq!(GVec::try_write_to_slice(U16Vec2::new(5, 6), &mut short), Err(SliceLenError { expected: 2, actual: 1 }));
+	assert_eq!(short, [0]);
+	let mut long = [0u16; 3];
+	assert_eq!(GVec::try_write_to_slice(U16Vec2::new(5, 6), &mut long), Ok(()));
+	assert_eq!(long, [5, 6, 0]);
+}
+
+#[test]
+fn chunks() {
+	let vecs = [UVec3::new(1, 2, 3), UVec3::new(4, 5, 6)];
+	let mut out = [0; 7];
+	assert_eq!(UVec3::write_chunks_to_slice(&vecs, &mut out), Ok(()));
+	assert_eq!(out, [1, 2, 3, 4, 5, 6, 0]);
+	assert_eq!(UVec3::write_chunks_to_slice(&vecs, &mut out[..5]), Err(SliceLenError { expected: 6, actual: 5 }));
+	assert_eq!(UVec3::write_chunks_to_slice(&[], &mut []), Ok(()));
+	#[cfg(feature = "alloc")]
+	{
+		assert_eq!(UVec3::from_slice_chunks(&out[..6]), Ok(vecs.to_vec()));
+		assert_eq!(UVec3::from_slice_chunks(&out), Err(SliceLenError { expected: 9, actual: 7 }));
+		assert_eq!(UVec3::from_slice_chunks(&out[..4]), Err(SliceLenError { expected: 6, actual: 4 }));
+		assert_eq!(UVec3::from_slice_chunks(&[]), Ok(Vec::new()));
+		assert_eq!(Vec2::from_slice_chunks(&[1.0, 2.0, 3.0, 4.0]), Ok(vec![Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]));
+	}
+}
+
+#[test]
+fn error_display() {
+	assert_eq!(SliceLenError { expected: 4, actual: 3 }.to_string(), "expected slice of length 4, got 3");
+}
@@ -0,0 +1,7 @@
+use glam::Vec3A;
+use glam_traits::PodVec;
+
+#[derive(PodVec)]
+struct Padded(Vec3A);
+
+fn main() {}